@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum Answer {
+    // Wide enough for every signed and unsigned 64 bit answer
+    Integer(i128),
+    Text(String),
+    List(Vec<Answer>),
+    Coordinate(i64, i64),
+    None,
+}
+
+impl Answer {
+    // The exact string the puzzle expects to be submitted
+    pub fn submission(&self) -> String {
+        match self {
+            Answer::Integer(value) => value.to_string(),
+            Answer::Text(text) => text.clone(),
+            Answer::List(items) => items
+                .iter()
+                .map(Answer::submission)
+                .collect::<Vec<_>>()
+                .join(","),
+            Answer::Coordinate(x, y) => format!("{},{}", x, y),
+            Answer::None => String::new(),
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Answer::Integer(value) => value.to_string(),
+            Answer::Text(text) => text.clone(),
+            Answer::List(items) => format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(Answer::pretty)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Answer::Coordinate(x, y) => format!("({}, {})", x, y),
+            Answer::None => "(no answer)".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(&self.submission())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.submission() == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.submission() == *other
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(i128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, u32, i64, u64);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        // usize is at most 64 bits wide on every supported target
+        Answer::from(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl<T: Into<Answer>> FromIterator<T> for Answer {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Answer::List(iter.into_iter().map(Into::into).collect())
+    }
+}
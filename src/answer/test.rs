@@ -0,0 +1,52 @@
+use crate::answer::Answer;
+
+#[test]
+fn test_submission() {
+    assert_eq!(Answer::from(42_u64).submission(), "42");
+    assert_eq!(Answer::from(-3_i32).submission(), "-3");
+    assert_eq!(Answer::Coordinate(6, 1).submission(), "6,1");
+    assert_eq!(Answer::None.submission(), "");
+    assert_eq!(
+        [4_i64, 6, 3].into_iter().collect::<Answer>().submission(),
+        "4,6,3"
+    );
+    assert_eq!(
+        ["co", "de", "ka", "ta"].into_iter().collect::<Answer>(),
+        "co,de,ka,ta"
+    );
+}
+
+#[test]
+fn test_pretty() {
+    assert_eq!(Answer::Coordinate(6, 1).pretty(), "(6, 1)");
+    assert_eq!(Answer::None.pretty(), "(no answer)");
+    assert_eq!(
+        ["co", "de"].into_iter().collect::<Answer>().pretty(),
+        "[co, de]"
+    );
+}
+
+#[test]
+fn test_json() {
+    let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+
+    assert_eq!(
+        json(Answer::from(42_u64)),
+        r#"{"type":"integer","value":42}"#
+    );
+    assert_eq!(
+        json(Answer::Coordinate(6, 1)),
+        r#"{"type":"coordinate","value":[6,1]}"#
+    );
+    assert_eq!(json(Answer::None), r#"{"type":"none"}"#);
+}
+
+#[test]
+fn test_wide_integer() {
+    let answer = Answer::from(u64::MAX);
+    assert_eq!(answer.submission(), "18446744073709551615");
+
+    let json = serde_json::to_string(&answer).unwrap();
+    assert_eq!(json, r#"{"type":"integer","value":18446744073709551615}"#);
+    assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+}
@@ -0,0 +1,65 @@
+use crate::solution::Part;
+use serde::Deserialize;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+// Answers may be written as TOML integers or strings, e.g. `part1 = 1234` or `part2 = "abc"`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RecordedAnswer {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnswersFile {
+    part1: Option<RecordedAnswer>,
+    part2: Option<RecordedAnswer>,
+}
+
+impl From<RecordedAnswer> for String {
+    fn from(answer: RecordedAnswer) -> Self {
+        match answer {
+            RecordedAnswer::Number(number) => number.to_string(),
+            RecordedAnswer::Text(text) => text,
+        }
+    }
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let file: AnswersFile = toml::from_str(content).map_err(|e| e.message().to_string())?;
+
+        Ok(Self {
+            part1: file.part1.map(String::from),
+            part2: file.part2.map(String::from),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|e| format!("Failed to parse '{}': {}", path.display(), e)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Failed to read '{}': {}", path.display(), e)),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
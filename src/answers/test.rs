@@ -0,0 +1,30 @@
+use crate::answers::Answers;
+use crate::solution::Part;
+
+#[test]
+fn test_parse() {
+    let answers = Answers::parse(
+        r#"
+        part1 = 1928
+        part2 = "co,de,ka,ta"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(answers.get(Part::One), Some("1928"));
+    assert_eq!(answers.get(Part::Two), Some("co,de,ka,ta"));
+}
+
+#[test]
+fn test_parse_partial() {
+    let answers = Answers::parse("part2 = 31").unwrap();
+
+    assert_eq!(answers.get(Part::One), None);
+    assert_eq!(answers.get(Part::Two), Some("31"));
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(Answers::parse("part3 = 1").is_err());
+    assert!(Answers::parse("part1 = [1, 2]").is_err());
+}
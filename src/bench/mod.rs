@@ -0,0 +1,210 @@
+use crate::error::SolveError;
+use crate::solution::{Part, Solution};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 2,
+            runs: 10,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    const ALL: [Stage; 3] = [
+        Stage::Parse,
+        Stage::Solve(Part::One),
+        Stage::Solve(Part::Two),
+    ];
+
+    fn key(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Solve(Part::One) => "part1",
+            Stage::Solve(Part::Two) => "part2",
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => fmt.pad("parse"),
+            Stage::Solve(part) => fmt.pad(&format!("part {}", part)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is required");
+        samples.sort();
+
+        // Nearest-rank percentile
+        let percentile = |p: usize| samples[(p * samples.len()).div_ceil(100).max(1) - 1];
+
+        Self {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+fn sample(
+    config: BenchConfig,
+    mut run: impl FnMut() -> Result<(), SolveError>,
+) -> Result<Stats, SolveError> {
+    for _ in 0..config.warmup {
+        run()?;
+    }
+
+    let mut samples = Vec::with_capacity(config.runs);
+    for _ in 0..config.runs.max(1) {
+        let start = Instant::now();
+        run()?;
+        samples.push(start.elapsed());
+    }
+
+    Ok(Stats::from_samples(samples))
+}
+
+pub fn bench_day(
+    solution: &dyn Solution,
+    input: &str,
+    config: BenchConfig,
+) -> Result<Vec<(Stage, Stats)>, SolveError> {
+    Stage::ALL
+        .iter()
+        .map(|&stage| {
+            let stats = sample(config, || match stage {
+                Stage::Parse => solution.parse(black_box(input)),
+                Stage::Solve(part) => solution
+                    .solve_part(part, black_box(input))
+                    .map(black_box)
+                    .map(|_| ()),
+            })?;
+            Ok((stage, stats))
+        })
+        .collect()
+}
+
+// Baselines are stored as `[dayN.stage]` tables with durations in nanoseconds
+#[derive(Default, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    days: BTreeMap<String, BTreeMap<String, Stats>>,
+}
+
+impl Baseline {
+    pub fn insert(&mut self, day: u8, stage: Stage, stats: Stats) {
+        self.days
+            .entry(format!("day{}", day))
+            .or_default()
+            .insert(stage.key().to_string(), stats);
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<&Stats> {
+        self.days
+            .get(&format!("day{}", day))
+            .and_then(|stages| stages.get(stage.key()))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        toml::from_str(&content)
+            .map_err(|e| format!("Failed to parse '{}': {}", path.display(), e.message()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, content).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Change {
+    pub previous: Duration,
+    pub current: Duration,
+    pub percent: f64,
+}
+
+impl Change {
+    pub fn between(previous: &Stats, current: &Stats) -> Self {
+        Self::new(previous.median, current.median)
+    }
+
+    pub fn new(previous: Duration, current: Duration) -> Self {
+        let percent = if previous.is_zero() {
+            0.0
+        } else {
+            (current.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0
+        };
+
+        Self {
+            previous,
+            current,
+            percent,
+        }
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent > threshold
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "{:.1?} -> {:.1?} ({:+.1}%)",
+            self.previous, self.current, self.percent
+        )
+    }
+}
@@ -0,0 +1,51 @@
+use crate::bench::{Baseline, Change, Stage, Stats};
+use crate::solution::Part;
+use std::time::Duration;
+
+fn millis(values: &[u64]) -> Vec<Duration> {
+    values.iter().map(|&v| Duration::from_millis(v)).collect()
+}
+
+#[test]
+fn test_stats() {
+    let stats = Stats::from_samples(millis(&[9, 1, 4, 3, 2, 5, 8, 7, 6, 10]));
+
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(5));
+    assert_eq!(stats.p95, Duration::from_millis(10));
+}
+
+#[test]
+fn test_stats_single_sample() {
+    let stats = Stats::from_samples(millis(&[3]));
+
+    assert_eq!(stats.min, stats.median);
+    assert_eq!(stats.median, stats.p95);
+}
+
+#[test]
+fn test_baseline_roundtrip() {
+    let mut baseline = Baseline::default();
+    let stats = Stats::from_samples(millis(&[1, 2, 3]));
+    baseline.insert(9, Stage::Parse, stats);
+    baseline.insert(9, Stage::Solve(Part::Two), stats);
+
+    let content = toml::to_string(&baseline).unwrap();
+    let parsed: Baseline = toml::from_str(&content).unwrap();
+
+    assert_eq!(parsed, baseline);
+    assert_eq!(parsed.get(9, Stage::Solve(Part::Two)), Some(&stats));
+    assert_eq!(parsed.get(9, Stage::Solve(Part::One)), None);
+}
+
+#[test]
+fn test_change() {
+    let previous = Stats::from_samples(millis(&[10]));
+    let current = Stats::from_samples(millis(&[12]));
+    let change = Change::between(&previous, &current);
+
+    assert!((change.percent - 20.0).abs() < 1e-9);
+    assert!(change.is_regression(10.0));
+    assert!(!change.is_regression(25.0));
+    assert!(!Change::between(&current, &previous).is_regression(10.0));
+}
@@ -0,0 +1,313 @@
+use crate::bench::{self, Baseline, Change};
+use crate::cli::{self, BenchArgs, Command, RecordArgs, RenderArgs, RunArgs, Target};
+use crate::generate::{self, GenerateArgs};
+use crate::inputs::{InputResolver, InputSource};
+use crate::params::ParamsConfig;
+use crate::registry;
+use crate::runner::{self, Report};
+use crate::solution::{Part, Solution};
+use crate::utils::render::Recorder;
+use crate::verify::{self, Outcome};
+use crate::watch;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+fn select_solutions(target: Target) -> Result<Vec<(u8, Box<dyn Solution>)>, String> {
+    match target {
+        Target::Day(day) => {
+            let solution =
+                registry::get_solution(day).ok_or(format!("Day {} is not registered", day))?;
+            Ok(vec![(day, solution)])
+        }
+        Target::All => Ok(registry::all_solutions()),
+    }
+}
+
+fn run_day(
+    day: u8,
+    solution: &dyn Solution,
+    resolver: &InputResolver,
+    args: &RunArgs,
+) -> Result<(), String> {
+    let input = resolver.read(day, &args.input)?;
+
+    let start = Instant::now();
+    let result = match args.part {
+        Some(part) => solution
+            .solve_part(part, &input)
+            .map(|answer| answer.to_string()),
+        None => solution.solve(&input),
+    }
+    .map_err(|e| e.with_day(day).to_string())?;
+    let elapsed = start.elapsed();
+
+    println!("{}", result);
+    eprintln!("Solved in {:.1?}", elapsed);
+
+    Ok(())
+}
+
+// Opens the input again for every part, so no part needs the whole input in memory
+fn run_day_streaming(
+    day: u8,
+    solution: &dyn Solution,
+    resolver: &InputResolver,
+    args: &RunArgs,
+) -> Result<(), String> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let start = Instant::now();
+    let mut answers = vec![];
+    for &part in &parts {
+        let mut reader = resolver.open(day, &args.input)?;
+        let answer = solution
+            .solve_part_streaming(part, &mut reader)
+            .ok_or(format!("Day {} part {} cannot be streamed", day, part))?
+            .map_err(|e| e.with_part(part).with_day(day).to_string())?;
+        answers.push((part, answer));
+    }
+    let elapsed = start.elapsed();
+
+    match answers.as_slice() {
+        [(_, answer)] => println!("{}", answer),
+        answers => {
+            let parts: Vec<String> = answers
+                .iter()
+                .map(|(part, answer)| format!("part {}:\n{}", part, answer))
+                .collect();
+            println!("{}", parts.join("\n\n"));
+        }
+    }
+    eprintln!("Solved in {:.1?}", elapsed);
+
+    Ok(())
+}
+
+fn finish_report(report: Report, path: Option<&Path>) -> Result<(), String> {
+    println!("{}", report);
+
+    if let Some(path) = path {
+        fs::write(path, report.to_json())
+            .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+    }
+
+    match report.failures() {
+        0 => Ok(()),
+        failures => Err(format!("{} part(s) failed or panicked", failures)),
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let resolver = InputResolver::from_env();
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    // Parameters given on the command line take precedence over the ones in the file
+    let mut params = match &args.param_file {
+        Some(path) => ParamsConfig::load(path)?,
+        None => ParamsConfig::default(),
+    };
+    if let Target::Day(day) = args.target {
+        for (key, value) in &args.params {
+            params.set(day, key, value);
+        }
+    }
+    params.validate()?;
+
+    match args.target {
+        Target::Day(day) => {
+            let solution = registry::configure(day, &params.overrides(day))?;
+
+            match &args.report {
+                Some(path) => {
+                    let input = resolver.read(day, &args.input);
+                    let report = runner::run_one(solution.as_ref(), day, input, &parts);
+                    finish_report(report, Some(path))
+                }
+                None if args.stream => run_day_streaming(day, solution.as_ref(), &resolver, &args),
+                None => run_day(day, solution.as_ref(), &resolver, &args),
+            }
+        }
+        Target::All => {
+            let report = runner::run_all(&resolver, &args.input, &parts, &params, args.parallel);
+            finish_report(report, args.report.as_deref())
+        }
+    }
+}
+
+fn params(target: Target) -> Result<(), String> {
+    for (day, solution) in select_solutions(target)? {
+        for (key, value) in solution.parameters() {
+            println!("day {:>2}: {} = {}", day, key, value);
+        }
+    }
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<(), String> {
+    print!("{}", generate::generate(&args)?);
+    Ok(())
+}
+
+fn render(args: RenderArgs) -> Result<(), String> {
+    let solution = registry::configure(args.day, &args.params)?;
+    let input = InputResolver::from_env().read(args.day, &args.input)?;
+
+    let image = solution
+        .render(&input)
+        .ok_or(format!(
+            "Day {} cannot be rendered, only days 14, 16 and 20 can",
+            args.day
+        ))?
+        .map_err(|e| e.with_day(args.day).to_string())?;
+
+    let image = image.scaled(args.scale);
+    image.save(&args.output)?;
+    eprintln!(
+        "Rendered day {} to '{}' ({}x{})",
+        args.day,
+        args.output.display(),
+        image.width(),
+        image.height()
+    );
+
+    Ok(())
+}
+
+fn record(args: RecordArgs) -> Result<(), String> {
+    let solution = registry::configure(args.day, &args.params)?;
+    let input = InputResolver::from_env().read(args.day, &args.input)?;
+    let mut recorder = Recorder::new(args.every, args.max_frames);
+
+    solution
+        .record(&input, &mut recorder)
+        .ok_or(format!(
+            "Day {} cannot be recorded, only days 6, 14, 15 and 18 can",
+            args.day
+        ))?
+        .map_err(|e| e.with_day(args.day).to_string())?;
+
+    recorder.save(&args.output, args.scale, args.delay)?;
+    eprintln!(
+        "Recorded {} frame(s) of day {} to '{}'",
+        recorder.frames().len(),
+        args.day,
+        args.output.display()
+    );
+
+    Ok(())
+}
+
+fn verify(target: Target) -> Result<(), String> {
+    let solutions = select_solutions(target)?;
+    let resolver = InputResolver::from_env();
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for (day, solution) in solutions {
+        for (part, outcome) in verify::verify_day(day, solution.as_ref(), &resolver) {
+            match outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Fail(_) => failed += 1,
+                Outcome::Missing(_) => missing += 1,
+            }
+            println!("day {:>2} part {}: {}", day, part, outcome);
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+
+    if failed > 0 {
+        return Err(format!("{} answer(s) did not match", failed));
+    }
+
+    Ok(())
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let solutions = select_solutions(args.target)?;
+    let resolver = InputResolver::from_env();
+    let previous = args.baseline.as_deref().map(Baseline::load).transpose()?;
+    let mut baseline = Baseline::default();
+    let mut regressions = 0;
+
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "day", "stage", "min", "median", "p95"
+    );
+
+    for (day, solution) in solutions {
+        let results = resolver
+            .read(day, &InputSource::default())
+            .and_then(|input| {
+                bench::bench_day(solution.as_ref(), &input, args.config)
+                    .map_err(|e| e.with_day(day).to_string())
+            });
+        let results = match results {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("Skipping day {}: {}", day, e);
+                continue;
+            }
+        };
+
+        for (stage, stats) in results {
+            let mut line = format!(
+                "{:>3}  {:<6}  {:>10.1?}  {:>10.1?}  {:>10.1?}",
+                day, stage, stats.min, stats.median, stats.p95
+            );
+
+            if let Some(previous) = previous.as_ref().and_then(|b| b.get(day, stage)) {
+                let change = Change::between(previous, &stats);
+                line += &format!("  {}", change);
+                if change.is_regression(args.threshold) {
+                    regressions += 1;
+                    line += "  REGRESSION";
+                }
+            }
+
+            println!("{}", line);
+            baseline.insert(day, stage, stats);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        baseline.save(path)?;
+    }
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} stage(s) regressed by more than {}%",
+            regressions, args.threshold
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn execute(command: Command) -> Result<(), String> {
+    match command {
+        Command::Run(args) => run(args),
+        Command::Verify(target) => verify(target),
+        Command::Bench(args) => bench(args),
+        Command::Watch(args) => watch::watch(args),
+        Command::Params(target) => params(target),
+        Command::Generate(args) => generate(args),
+        Command::Render(args) => render(args),
+        Command::Record(args) => record(args),
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,547 @@
+use crate::bench::BenchConfig;
+use crate::generate::GenerateArgs;
+use crate::inputs::InputSource;
+use crate::logging::LogConfig;
+use crate::params::Overrides;
+use crate::registry::DAYS;
+use crate::solution::Part;
+use crate::watch::WatchArgs;
+use log::LevelFilter;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+mod commands;
+#[cfg(test)]
+mod test;
+
+pub use commands::execute;
+
+pub static USAGE: &str = "Usage:
+    run <day> [--part 1|2] [--input <path>|-] [--input-name <name>] [--report <path>]
+              [--param <name>=<value>]... [--param-file <path>] [--stream]
+    run all [--part 1|2] [--input-name <name>] [--parallel] [--report <path>] [--param-file <path>]
+    verify [<day>|all]
+    bench [<day>|all] [--runs <n>] [--warmup <n>] [--save <path>] [--baseline <path>] [--threshold <percent>]
+    watch <day> [--interval <ms>]
+    params [<day>|all]
+    generate <day> [--size <n>] [--seed <n>] [--swaps <n>]
+    render <day> --output <path> [--input <path>|-] [--input-name <name>] [--scale <n>]
+                 [--param <name>=<value>]...
+    record <day> --output <path> [--input <path>|-] [--input-name <name>] [--every <n>]
+                 [--max-frames <n>] [--scale <n>] [--delay <ms>] [--param <name>=<value>]...
+
+Logging options, accepted with any command:
+    -v, -vv, --verbose        raise the log level (repeat for more detail)
+    --log-level <level>       off, error, warn, info, debug or trace
+    --log-day <day>[,<day>]   only show diagnostics of these days
+    --log-file <path>         write diagnostics to a file instead of stderr
+
+'--stream' reads the input line by line, for inputs too large to load at once. Only days 1, 2
+and 22 support it.
+
+Parameter files contain a [dayN] table per day, e.g. '[day18]' followed by 'bytes = 12'.
+'params' lists the parameters of every day with their defaults.
+
+'generate' prints a random puzzle input, the same seed always gives the same input. '--size'
+scales the input, '--swaps' sets the number of swapped gate outputs of day 24.
+
+'render' draws the grid of days 14, 16 and 20 to a .png or .ppm file, with every tile drawn as
+a square of '--scale' pixels (4 by default).
+
+'record' captures the simulation of days 6, 14, 15 and 18 to an animated .gif or a .txt file with
+one frame after the other. Only every '--every'-th step is recorded, up to '--max-frames' frames
+(500 by default), every frame is shown for '--delay' milliseconds (100 by default), rounded up to
+hundredths of a second.
+
+Inputs are looked up as <name>.txt in data/dayNN or data/dayN, where <name> defaults to
+'input'. Set AOC_DATA_DIR to use another data directory, '--input -' reads from stdin.";
+
+#[derive(Debug, PartialEq)]
+pub enum Target {
+    Day(u8),
+    All,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArgs {
+    pub target: Target,
+    pub part: Option<Part>,
+    pub input: InputSource,
+    pub parallel: bool,
+    pub report: Option<PathBuf>,
+    pub params: Overrides,
+    pub param_file: Option<PathBuf>,
+    pub stream: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    pub target: Target,
+    pub config: BenchConfig,
+    pub save: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    pub threshold: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RenderArgs {
+    pub day: u8,
+    pub input: InputSource,
+    pub output: PathBuf,
+    pub scale: usize,
+    pub params: Overrides,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RecordArgs {
+    pub day: u8,
+    pub input: InputSource,
+    pub output: PathBuf,
+    pub every: usize,
+    pub max_frames: usize,
+    pub scale: usize,
+    // In hundredths of a second, the unit GIF delays are stored in
+    pub delay: u16,
+    pub params: Overrides,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(Target),
+    Bench(BenchArgs),
+    Watch(WatchArgs),
+    Params(Target),
+    Generate(GenerateArgs),
+    Render(RenderArgs),
+    Record(RecordArgs),
+    Help,
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    value
+        .parse::<u8>()
+        .ok()
+        .filter(|day| DAYS.contains(day))
+        .ok_or_else(|| format!("Invalid day '{}', expected a number in {:?}", value, DAYS))
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part '{}', expected 1 or 2", value)),
+    }
+}
+
+fn parse_value<T: FromStr>(arg: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value '{}' for '{}'", value, arg))
+}
+
+fn parse_target(value: &str) -> Result<Target, String> {
+    match value {
+        "all" => Ok(Target::All),
+        day => Ok(Target::Day(parse_day(day)?)),
+    }
+}
+
+// The options that choose the input of a single day and override its parameters
+#[derive(Default)]
+struct InputOptions {
+    input: Option<InputSource>,
+    input_name: Option<InputSource>,
+    params: Overrides,
+}
+
+impl InputOptions {
+    // Returns whether `arg` is one of these options, `value` reads the value that follows it
+    fn parse_arg(
+        &mut self,
+        arg: &str,
+        mut value: impl FnMut() -> Result<String, String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--input" | "-i" => {
+                self.input = Some(match value()?.as_str() {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::Path(PathBuf::from(path)),
+                })
+            }
+            "--input-name" => self.input_name = Some(InputSource::Named(value()?)),
+            "--param" => {
+                let param = value()?;
+                let (key, value) = param
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid parameter '{}', expected name=value", param))?;
+                self.params.insert(key.to_string(), value.to_string());
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn finish(self) -> Result<(InputSource, Overrides), String> {
+        if self.input.is_some() && self.input_name.is_some() {
+            return Err("'--input' cannot be combined with '--input-name'".into());
+        }
+
+        Ok((
+            self.input.or(self.input_name).unwrap_or_default(),
+            self.params,
+        ))
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let target = match args.next() {
+        Some(target) => parse_target(&target)?,
+        None => return Err("Missing day to run".into()),
+    };
+
+    let mut part = None;
+    let mut options = InputOptions::default();
+    let mut parallel = false;
+    let mut report = None;
+    let mut param_file = None;
+    let mut stream = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{}'", arg))
+        };
+
+        if options.parse_arg(&arg, &mut value)? {
+            continue;
+        }
+
+        match arg.as_str() {
+            "--part" | "-p" => part = Some(parse_part(&value()?)?),
+            "--parallel" => parallel = true,
+            "--report" => report = Some(PathBuf::from(value()?)),
+            "--param-file" => param_file = Some(PathBuf::from(value()?)),
+            "--stream" => stream = true,
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    if target == Target::All && options.input.is_some() {
+        return Err("'--input' cannot be combined with 'run all'".into());
+    }
+
+    let (input, params) = options.finish()?;
+
+    if target != Target::All && parallel {
+        return Err("'--parallel' requires 'run all'".into());
+    }
+
+    if target == Target::All && !params.is_empty() {
+        return Err("'--param' cannot be combined with 'run all', use '--param-file'".into());
+    }
+
+    if stream && (target == Target::All || report.is_some()) {
+        return Err(
+            "'--stream' requires a single day and cannot be combined with '--report'".into(),
+        );
+    }
+
+    // Standard input can only be streamed once
+    if stream && input == InputSource::Stdin && part.is_none() {
+        return Err("'--stream' with '--input -' requires '--part'".into());
+    }
+
+    Ok(Command::Run(RunArgs {
+        target,
+        part,
+        input,
+        parallel,
+        report,
+        params,
+        param_file,
+        stream,
+    }))
+}
+
+fn parse_optional_target(mut args: impl Iterator<Item = String>) -> Result<Target, String> {
+    let target = match args.next() {
+        Some(target) => parse_target(&target)?,
+        None => Target::All,
+    };
+
+    match args.next() {
+        Some(arg) => Err(format!("Unknown argument '{}'", arg)),
+        None => Ok(target),
+    }
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut bench_args = BenchArgs {
+        target: Target::All,
+        config: BenchConfig::default(),
+        save: None,
+        baseline: None,
+        threshold: 10.0,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--runs" => bench_args.config.runs = parse_value(&arg, &value()?)?,
+            "--warmup" => bench_args.config.warmup = parse_value(&arg, &value()?)?,
+            "--save" => bench_args.save = Some(PathBuf::from(value()?)),
+            "--baseline" => bench_args.baseline = Some(PathBuf::from(value()?)),
+            "--threshold" => bench_args.threshold = parse_value(&arg, &value()?)?,
+            _ if !arg.starts_with('-') => bench_args.target = parse_target(&arg)?,
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    if bench_args.config.runs == 0 {
+        return Err("'--runs' must be at least 1".into());
+    }
+
+    Ok(Command::Bench(bench_args))
+}
+
+fn parse_watch(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = match args.next() {
+        Some(day) => parse_day(&day)?,
+        None => return Err("Missing day to watch".into()),
+    };
+
+    let mut watch_args = WatchArgs::new(day);
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--interval" => {
+                watch_args.interval = Duration::from_millis(parse_value(&arg, &value()?)?)
+            }
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    if watch_args.interval.is_zero() {
+        return Err("'--interval' must be at least 1".into());
+    }
+
+    Ok(Command::Watch(watch_args))
+}
+
+fn parse_generate(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = match args.next() {
+        Some(day) => parse_day(&day)?,
+        None => return Err("Missing day to generate".into()),
+    };
+
+    let mut generate_args = GenerateArgs::new(day);
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--size" => generate_args.size = parse_value(&arg, &value()?)?,
+            "--seed" => generate_args.seed = parse_value(&arg, &value()?)?,
+            "--swaps" => generate_args.swaps = Some(parse_value(&arg, &value()?)?),
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    if generate_args.size == 0 {
+        return Err("'--size' must be at least 1".into());
+    }
+
+    if generate_args.swaps.is_some() && day != 24 {
+        return Err("'--swaps' only applies to day 24".into());
+    }
+
+    Ok(Command::Generate(generate_args))
+}
+
+fn parse_render(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = match args.next() {
+        Some(day) => parse_day(&day)?,
+        None => return Err("Missing day to render".into()),
+    };
+
+    let mut options = InputOptions::default();
+    let mut output = None;
+    let mut scale = 4;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{}'", arg))
+        };
+
+        if options.parse_arg(&arg, &mut value)? {
+            continue;
+        }
+
+        match arg.as_str() {
+            "--output" | "-o" => output = Some(PathBuf::from(value()?)),
+            "--scale" => scale = parse_value(&arg, &value()?)?,
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    let (input, params) = options.finish()?;
+
+    if scale == 0 {
+        return Err("'--scale' must be at least 1".into());
+    }
+
+    Ok(Command::Render(RenderArgs {
+        day,
+        input,
+        output: output.ok_or("Missing '--output' path of the image")?,
+        scale,
+        params,
+    }))
+}
+
+fn parse_record(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = match args.next() {
+        Some(day) => parse_day(&day)?,
+        None => return Err("Missing day to record".into()),
+    };
+
+    let mut options = InputOptions::default();
+    let mut output = None;
+    let mut every = 1;
+    let mut max_frames = 500;
+    let mut scale = 4;
+    let mut delay: u64 = 100;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{}'", arg))
+        };
+
+        if options.parse_arg(&arg, &mut value)? {
+            continue;
+        }
+
+        match arg.as_str() {
+            "--output" | "-o" => output = Some(PathBuf::from(value()?)),
+            "--every" => every = parse_value(&arg, &value()?)?,
+            "--max-frames" => max_frames = parse_value(&arg, &value()?)?,
+            "--scale" => scale = parse_value(&arg, &value()?)?,
+            "--delay" => delay = parse_value(&arg, &value()?)?,
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+
+    let (input, params) = options.finish()?;
+
+    if every == 0 {
+        return Err("'--every' must be at least 1".into());
+    }
+
+    if max_frames == 0 {
+        return Err("'--max-frames' must be at least 1".into());
+    }
+
+    if scale == 0 {
+        return Err("'--scale' must be at least 1".into());
+    }
+
+    // Rounded up, so short delays do not become the zero delay that viewers replace by their own
+    let delay =
+        u16::try_from(delay.div_ceil(10).max(1)).map_err(|_| "'--delay' must be at most 655350")?;
+
+    Ok(Command::Record(RecordArgs {
+        day,
+        input,
+        output: output.ok_or("Missing '--output' path of the recording")?,
+        every,
+        max_frames,
+        scale,
+        delay,
+        params,
+    }))
+}
+
+fn raise_level(level: LevelFilter) -> LevelFilter {
+    match level {
+        LevelFilter::Off | LevelFilter::Error | LevelFilter::Warn => LevelFilter::Info,
+        LevelFilter::Info => LevelFilter::Debug,
+        LevelFilter::Debug | LevelFilter::Trace => LevelFilter::Trace,
+    }
+}
+
+// Counts the `v`s of `-v`, `-vv`, `-vvv`
+fn verbosity(arg: &str) -> Option<usize> {
+    let flags = arg.strip_prefix('-')?;
+    (!flags.is_empty() && flags.chars().all(|c| c == 'v')).then_some(flags.len())
+}
+
+// Splits the logging options off, so they can be given anywhere on the command line
+pub fn parse_log_args(
+    args: impl IntoIterator<Item = String>,
+) -> Result<(LogConfig, Vec<String>), String> {
+    let mut config = LogConfig::default();
+    let mut remaining = Vec::new();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{}'", arg))
+        };
+
+        match arg.as_str() {
+            "--verbose" => config.level = raise_level(config.level),
+            "--log-level" => config.level = parse_value(&arg, &value()?)?,
+            "--log-day" => {
+                for day in value()?.split(',') {
+                    config.days.push(parse_day(day)?);
+                }
+            }
+            "--log-file" => config.file = Some(PathBuf::from(value()?)),
+            _ => match verbosity(&arg) {
+                Some(count) => {
+                    for _ in 0..count {
+                        config.level = raise_level(config.level);
+                    }
+                }
+                None => remaining.push(arg),
+            },
+        }
+    }
+
+    Ok((config, remaining))
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("verify") => parse_optional_target(args).map(Command::Verify),
+        Some("bench") => parse_bench(args),
+        Some("watch") => parse_watch(args),
+        Some("params") => parse_optional_target(args).map(Command::Params),
+        Some("generate") => parse_generate(args),
+        Some("render") => parse_render(args),
+        Some("record") => parse_record(args),
+        Some("help") | Some("--help") | Some("-h") => Ok(Command::Help),
+        Some(command) => Err(format!("Unknown command '{}'", command)),
+        None => Err("Missing command".into()),
+    }
+}
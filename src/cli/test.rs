@@ -0,0 +1,306 @@
+use crate::bench::BenchConfig;
+use crate::cli::{
+    parse_args, parse_log_args, BenchArgs, Command, RecordArgs, RenderArgs, RunArgs, Target,
+};
+use crate::generate::GenerateArgs;
+use crate::inputs::InputSource;
+use crate::logging::LogConfig;
+use crate::params::Overrides;
+use crate::solution::Part;
+use crate::watch::WatchArgs;
+use log::LevelFilter;
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn parse(args: &str) -> Result<Command, String> {
+    parse_args(args.split_whitespace().map(String::from))
+}
+
+#[test]
+fn test_run_day() {
+    assert_eq!(
+        parse("run 18 --part 2 --input data/alt.txt"),
+        Ok(Command::Run(RunArgs {
+            target: Target::Day(18),
+            part: Some(Part::Two),
+            input: InputSource::Path(PathBuf::from("data/alt.txt")),
+            parallel: false,
+            report: None,
+            params: Overrides::new(),
+            param_file: None,
+            stream: false,
+        }))
+    );
+    assert_eq!(
+        parse("run 5 --report day5.json"),
+        Ok(Command::Run(RunArgs {
+            target: Target::Day(5),
+            part: None,
+            input: InputSource::default(),
+            parallel: false,
+            report: Some(PathBuf::from("day5.json")),
+            params: Overrides::new(),
+            param_file: None,
+            stream: false,
+        }))
+    );
+}
+
+#[test]
+fn test_run_all() {
+    assert_eq!(
+        parse("run all"),
+        Ok(Command::Run(RunArgs {
+            target: Target::All,
+            part: None,
+            input: InputSource::default(),
+            parallel: false,
+            report: None,
+            params: Overrides::new(),
+            param_file: None,
+            stream: false,
+        }))
+    );
+    assert_eq!(
+        parse("run all --parallel --report report.json"),
+        Ok(Command::Run(RunArgs {
+            target: Target::All,
+            part: None,
+            input: InputSource::default(),
+            parallel: true,
+            report: Some(PathBuf::from("report.json")),
+            params: Overrides::new(),
+            param_file: None,
+            stream: false,
+        }))
+    );
+}
+
+#[test]
+fn test_run_input_sources() {
+    let input = |args: &str| match parse(args) {
+        Ok(Command::Run(run_args)) => Ok(run_args.input),
+        other => Err(other),
+    };
+
+    assert_eq!(input("run 3 --input -"), Ok(InputSource::Stdin));
+    assert_eq!(
+        input("run 3 --input-name alt1"),
+        Ok(InputSource::Named("alt1".into()))
+    );
+    assert_eq!(
+        input("run all --input-name example"),
+        Ok(InputSource::Named("example".into()))
+    );
+}
+
+#[test]
+fn test_run_params() {
+    let run_args =
+        match parse("run 20 --param min_saving=64 --param part1_cheat=3 --param-file p.toml") {
+            Ok(Command::Run(run_args)) => run_args,
+            other => panic!("unexpected {:?}", other),
+        };
+
+    assert_eq!(
+        run_args.params,
+        Overrides::from([
+            ("min_saving".to_string(), "64".to_string()),
+            ("part1_cheat".to_string(), "3".to_string())
+        ])
+    );
+    assert_eq!(run_args.param_file, Some(PathBuf::from("p.toml")));
+    assert_eq!(parse("params 14"), Ok(Command::Params(Target::Day(14))));
+    assert_eq!(parse("params"), Ok(Command::Params(Target::All)));
+}
+
+#[test]
+fn test_run_stream() {
+    match parse("run 22 --stream --input - --part 1") {
+        Ok(Command::Run(run_args)) => {
+            assert!(run_args.stream);
+            assert_eq!(run_args.input, InputSource::Stdin);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_verify() {
+    assert_eq!(parse("verify"), Ok(Command::Verify(Target::All)));
+    assert_eq!(parse("verify all"), Ok(Command::Verify(Target::All)));
+    assert_eq!(parse("verify 9"), Ok(Command::Verify(Target::Day(9))));
+}
+
+#[test]
+fn test_bench() {
+    assert_eq!(
+        parse("bench 22 --runs 5 --warmup 0 --baseline base.toml --threshold 2.5"),
+        Ok(Command::Bench(BenchArgs {
+            target: Target::Day(22),
+            config: BenchConfig { warmup: 0, runs: 5 },
+            save: None,
+            baseline: Some(PathBuf::from("base.toml")),
+            threshold: 2.5,
+        }))
+    );
+}
+
+#[test]
+fn test_watch() {
+    assert_eq!(parse("watch 12"), Ok(Command::Watch(WatchArgs::new(12))));
+    assert_eq!(
+        parse("watch 12 --interval 200"),
+        Ok(Command::Watch(WatchArgs {
+            day: 12,
+            interval: Duration::from_millis(200),
+        }))
+    );
+}
+
+#[test]
+fn test_generate() {
+    assert_eq!(
+        parse("generate 9"),
+        Ok(Command::Generate(GenerateArgs::new(9)))
+    );
+    assert_eq!(
+        parse("generate 24 --size 45 --seed 7 --swaps 2"),
+        Ok(Command::Generate(GenerateArgs {
+            day: 24,
+            size: 45,
+            seed: 7,
+            swaps: Some(2),
+        }))
+    );
+}
+
+#[test]
+fn test_render() {
+    assert_eq!(
+        parse("render 16 --output maze.png"),
+        Ok(Command::Render(RenderArgs {
+            day: 16,
+            input: InputSource::default(),
+            output: PathBuf::from("maze.png"),
+            scale: 4,
+            params: Overrides::new(),
+        }))
+    );
+    assert_eq!(
+        parse("render 14 -o tree.ppm --input-name alt1 --scale 2 --param tree_threshold=0.3"),
+        Ok(Command::Render(RenderArgs {
+            day: 14,
+            input: InputSource::Named("alt1".to_string()),
+            output: PathBuf::from("tree.ppm"),
+            scale: 2,
+            params: Overrides::from([("tree_threshold".to_string(), "0.3".to_string())]),
+        }))
+    );
+}
+
+#[test]
+fn test_record() {
+    assert_eq!(
+        parse("record 6 -o guard.gif"),
+        Ok(Command::Record(RecordArgs {
+            day: 6,
+            input: InputSource::default(),
+            output: PathBuf::from("guard.gif"),
+            every: 1,
+            max_frames: 500,
+            scale: 4,
+            delay: 10,
+            params: Overrides::new(),
+        }))
+    );
+    assert_eq!(
+        parse("record 18 -o bytes.txt --every 10 --max-frames 20 --delay 40 --param bytes=12"),
+        Ok(Command::Record(RecordArgs {
+            day: 18,
+            input: InputSource::default(),
+            output: PathBuf::from("bytes.txt"),
+            every: 10,
+            max_frames: 20,
+            scale: 4,
+            delay: 4,
+            params: Overrides::from([("bytes".to_string(), "12".to_string())]),
+        }))
+    );
+}
+
+#[test]
+fn test_record_delay() {
+    let delay = |arg: &str| match parse(&format!("record 6 -o guard.gif --delay {}", arg)) {
+        Ok(Command::Record(args)) => Some(args.delay),
+        _ => None,
+    };
+
+    // GIF delays are whole hundredths of a second, shorter delays are rounded up
+    assert_eq!(delay("5"), Some(1));
+    assert_eq!(delay("0"), Some(1));
+    assert_eq!(delay("15"), Some(2));
+    assert_eq!(delay("655350"), Some(65535));
+    assert_eq!(delay("655351"), None);
+}
+
+#[test]
+fn test_log_args() {
+    let args = "run 14 -v --log-day 14,24 --part 2 -v --log-file debug.log";
+    let (config, remaining) = parse_log_args(args.split_whitespace().map(String::from)).unwrap();
+
+    assert_eq!(
+        config,
+        LogConfig {
+            level: LevelFilter::Debug,
+            days: vec![14, 24],
+            file: Some(PathBuf::from("debug.log")),
+        }
+    );
+    assert_eq!(remaining, vec!["run", "14", "--part", "2"]);
+
+    let (config, _) = parse_log_args(["-vvv".to_string()]).unwrap();
+    assert_eq!(config.level, LevelFilter::Trace);
+
+    let (config, _) = parse_log_args(["--log-level".to_string(), "trace".to_string()]).unwrap();
+    assert_eq!(config.level, LevelFilter::Trace);
+}
+
+#[test]
+fn test_invalid_arguments() {
+    assert!(parse("run 26").is_err());
+    assert!(parse("run 1 --part 3").is_err());
+    assert!(parse("run 1 --input").is_err());
+    assert!(parse("run all --input data/alt.txt").is_err());
+    assert!(parse("run 1 --parallel").is_err());
+    assert!(parse("run 1 --input a.txt --input-name alt1").is_err());
+    assert!(parse("run all --input -").is_err());
+    assert!(parse("run 20 --param min_saving").is_err());
+    assert!(parse("run all --param min_saving=64").is_err());
+    assert!(parse("run all --stream").is_err());
+    assert!(parse("run 1 --stream --report day1.json").is_err());
+    assert!(parse("run 1 --stream --input -").is_err());
+    assert!(parse("verify 0").is_err());
+    assert!(parse("verify 1 2").is_err());
+    assert!(parse("bench --runs 0").is_err());
+    assert!(parse("bench --threshold fast").is_err());
+    assert!(parse("watch").is_err());
+    assert!(parse("watch all").is_err());
+    assert!(parse("watch 1 --interval 0").is_err());
+    assert!(parse("generate").is_err());
+    assert!(parse("generate 9 --size 0").is_err());
+    assert!(parse("generate 9 --swaps 4").is_err());
+    assert!(parse("generate 24 --seed -1").is_err());
+    assert!(parse("render 16").is_err());
+    assert!(parse("render 16 -o maze.png --scale 0").is_err());
+    assert!(parse("render 16 -o maze.png --input - --input-name alt1").is_err());
+    assert!(parse("record 6").is_err());
+    assert!(parse("record 6 -o guard.gif --every 0").is_err());
+    assert!(parse("record 6 -o guard.gif --max-frames 0").is_err());
+    assert!(parse("record 6 -o guard.gif --delay 1000000").is_err());
+    assert!(parse("record 6 -o guard.gif --input a.txt --input-name alt1").is_err());
+    assert!(parse("record 18 -o bytes.txt --param bytes").is_err());
+    assert!(parse("walk 1").is_err());
+    assert!(parse_log_args(["--log-level".to_string(), "loud".to_string()]).is_err());
+    assert!(parse_log_args(["--log-day".to_string(), "0".to_string()]).is_err());
+}
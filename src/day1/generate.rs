@@ -0,0 +1,16 @@
+use crate::utils::random::Rng;
+
+// `size` pairs of location IDs, the right list repeats some IDs of the left one
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<u32> = (0..size).map(|_| rng.range(10000..=99999)).collect();
+
+    left.iter()
+        .map(|&l| {
+            let r = match rng.chance(0.3) {
+                true => *rng.choose(&left),
+                false => rng.range(10000..=99999),
+            };
+            format!("{}   {}\n", l, r)
+        })
+        .collect()
+}
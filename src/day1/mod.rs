@@ -1,30 +1,32 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Part, Solution};
+use crate::utils::parsing::{for_each_line, non_empty_lines, parse_number};
 use std::collections::HashMap;
+use std::io::BufRead;
+pub mod generate;
 #[cfg(test)]
 mod test;
 pub struct Day1 {}
 
-fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
-    let numbers: Vec<_> = input
-        .trim()
-        .lines()
-        .skip_while(|&x| x.is_empty())
-        .map(|line| {
-            let mut line_split = line.trim().split_whitespace();
-            let a = line_split
-                .next()
-                .expect("Missing first number")
-                .parse::<i32>()
-                .expect("First value is not a valid number");
-            let b = line_split
-                .next()
-                .expect("Missing second number")
-                .parse::<i32>()
-                .expect("Second value is not a valid number");
-
-            (a, b)
-        })
-        .collect();
+fn parse_line(input: &str, line: &str) -> Result<(i32, i32), SolveError> {
+    let mut line_split = line.split_whitespace();
+    let a = line_split
+        .next()
+        .ok_or_else(|| SolveError::invalid_input(input, line, "Missing first number"))?;
+    let b = line_split
+        .next()
+        .ok_or_else(|| SolveError::invalid_input(input, line, "Missing second number"))?;
+
+    Ok((
+        parse_number::<i32>(input, a)?,
+        parse_number::<i32>(input, b)?,
+    ))
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), SolveError> {
+    let numbers = non_empty_lines(input)
+        .map(|line| parse_line(input, line))
+        .collect::<Result<Vec<_>, SolveError>>()?;
 
     let mut left_numbers = numbers.iter().map(|v| v.0).collect::<Vec<i32>>();
     left_numbers.sort();
@@ -32,12 +34,65 @@ fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
     let mut right_numbers = numbers.iter().map(|v| v.1).collect::<Vec<i32>>();
     right_numbers.sort();
 
-    (left_numbers, right_numbers)
+    Ok((left_numbers, right_numbers))
+}
+
+// Part 1 only keeps the numbers, part 2 only counts how often each number occurs
+fn solve_streaming(part: Part, reader: &mut dyn BufRead) -> Result<Answer, SolveError> {
+    let mut lists = (vec![], vec![]);
+    let mut counts: (HashMap<i32, u64>, HashMap<i32, u64>) = (HashMap::new(), HashMap::new());
+
+    for_each_line(reader, |line| {
+        let (a, b) = parse_line(line, line.trim())?;
+        match part {
+            Part::One => {
+                lists.0.push(a);
+                lists.1.push(b);
+            }
+            Part::Two => {
+                *counts.0.entry(a).or_default() += 1;
+                *counts.1.entry(b).or_default() += 1;
+            }
+        }
+        Ok(())
+    })?;
+
+    Ok(match part {
+        Part::One => {
+            lists.0.sort();
+            lists.1.sort();
+            lists
+                .0
+                .iter()
+                .zip(lists.1)
+                .map(|(a, b)| a.abs_diff(b) as u64)
+                .sum::<u64>()
+                .into()
+        }
+        Part::Two => counts
+            .0
+            .iter()
+            .map(|(v, count)| *v as u64 * count * counts.1.get(v).unwrap_or(&0))
+            .sum::<u64>()
+            .into(),
+    })
 }
 
 impl Solution for Day1 {
-    fn solve_part1(&self, input: &str) -> String {
-        let (left_numbers, right_numbers) = parse_input(input);
+    fn solve_part_streaming(
+        &self,
+        part: Part,
+        reader: &mut dyn BufRead,
+    ) -> Option<Result<Answer, SolveError>> {
+        Some(solve_streaming(part, reader))
+    }
+
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let (left_numbers, right_numbers) = parse_input(input)?;
 
         let differences: Vec<i32> = left_numbers
             .iter()
@@ -47,11 +102,11 @@ impl Solution for Day1 {
 
         let distance_sum: i32 = differences.iter().sum();
 
-        distance_sum.to_string()
+        Ok(distance_sum.into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let (left_numbers, right_numbers) = parse_input(input);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let (left_numbers, right_numbers) = parse_input(input)?;
 
         let right_occurrences: HashMap<i32, u32> = right_numbers
             .iter()
@@ -63,6 +118,6 @@ impl Solution for Day1 {
             .map(|v| (*v as u32) * right_occurrences.get(v).unwrap_or(&0))
             .sum();
 
-        similarity_score.to_string()
+        Ok(similarity_score.into())
     }
 }
@@ -1,5 +1,6 @@
 use crate::day1::Day1;
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Part, Solution};
 
 static SAMPLE: &str = r#"
     3   4
@@ -11,13 +12,38 @@ static SAMPLE: &str = r#"
     "#;
 
 #[test]
-fn test_part1() {
+fn test_invalid_number() {
     let day = Day1 {};
-    assert_eq!(day.solve_part1(SAMPLE), "11");
+    let error = day.solve_part1("3   4\n4   x3\n").unwrap_err();
+    assert!(matches!(
+        error,
+        SolveError::InvalidInput {
+            line: 2,
+            column: 5,
+            ..
+        }
+    ));
+    assert!(matches!(error, SolveError::InvalidInput { .. }));
 }
 
 #[test]
-fn test_part2() {
+fn test_streaming() {
     let day = Day1 {};
-    assert_eq!(day.solve_part2(SAMPLE), "31");
+    for (part, expected) in [(Part::One, "11"), (Part::Two, "31")] {
+        let answer = day.solve_part_streaming(part, &mut SAMPLE.as_bytes());
+        assert_eq!(answer.unwrap().unwrap(), expected);
+    }
+
+    let error = day
+        .solve_part_streaming(Part::Two, &mut "3   4\n4   x3\n".as_bytes())
+        .unwrap()
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        SolveError::InvalidInput {
+            line: 2,
+            column: 5,
+            ..
+        }
+    ));
 }
@@ -0,0 +1,35 @@
+use crate::generate::render_grid;
+use crate::utils::random::Rng;
+
+// A `size` by `size` map of random heights, crossed by hiking trails from 0 up to 9
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut grid: Vec<Vec<u32>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.range(0..=9)).collect())
+        .collect();
+
+    for _ in 0..size {
+        let mut row = rng.below(size as u64) as usize;
+        let mut col = rng.below(size as u64) as usize;
+
+        for height in 0..=9 {
+            grid[row][col] = height;
+
+            let (dr, dc) = *rng.choose(&[(0, 1), (1, 0), (0, -1), (-1, 0)]);
+            match (row.checked_add_signed(dr), col.checked_add_signed(dc)) {
+                (Some(r), Some(c)) if r < size && c < size => (row, col) = (r, c),
+                _ => break,
+            }
+        }
+    }
+
+    let grid: Vec<Vec<char>> = grid
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&h| char::from_digit(h, 10).unwrap())
+                .collect()
+        })
+        .collect();
+    render_grid(&grid)
+}
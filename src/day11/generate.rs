@@ -0,0 +1,10 @@
+use crate::utils::random::Rng;
+
+// `size` stones with engravings of up to seven digits
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| rng.range(0..=9_999_999u64).to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+        + "\n"
+}
@@ -1,11 +1,27 @@
-use crate::solution::Solution;
-use memoize::memoize;
-
+use crate::error::SolveError;
+use crate::params::{at_least, parameters, Parameters};
+use crate::solution::{Answer, Solution};
+use crate::utils::memo::Memo;
+use crate::utils::parsing::parse_number;
+use log::debug;
+
+pub mod generate;
 #[cfg(test)]
 mod test;
-pub struct Day11 {}
 
-struct PlutonianPebbles {
+parameters! {
+    pub struct Day11Params {
+        part1_blinks: u32 = 25 => at_least(1),
+        part2_blinks: u32 = 75 => at_least(1),
+    }
+}
+
+#[derive(Default)]
+pub struct Day11 {
+    pub params: Day11Params,
+}
+
+pub struct PlutonianPebbles {
     pebbles: Vec<u64>,
 }
 
@@ -19,7 +35,7 @@ fn replace_if_zero(pebble: u64) -> Option<u64> {
 
 fn split_even_length(pebble: u64) -> Option<(u64, u64)> {
     let length = pebble.checked_ilog10().unwrap_or(0) + 1;
-    if length % 2 == 0 {
+    if length.is_multiple_of(2) {
         // even length pebble engraving
         let half = length / 2;
         let divisor: u64 = 10_u64.pow(half);
@@ -37,7 +53,7 @@ fn multiply(pebble: u64) -> u64 {
     pebble * 2024
 }
 
-fn change_stone(pebble: u64) -> Vec<u64> {
+pub fn change_stone(pebble: u64) -> Vec<u64> {
     if let Some(new_stone) = replace_if_zero(pebble) {
         return vec![new_stone];
     }
@@ -49,43 +65,68 @@ fn change_stone(pebble: u64) -> Vec<u64> {
     vec![multiply(pebble)]
 }
 
-#[memoize]
-fn blink_pebble(pebble: u64, count: u32) -> u64 {
-    let new_pebbles = change_stone(pebble);
-    if count == 1 {
-        // println!("{}", new_pebbles.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(" "));
-        return new_pebbles.len() as u64;
-    }
+#[derive(Default)]
+pub struct PebbleCounter {
+    cache: Memo<(u64, u32), u64>,
+}
 
-    new_pebbles
-        .iter()
-        .map(|p| blink_pebble(*p, count - 1))
-        .sum()
+impl PebbleCounter {
+    pub fn blink_pebble(&mut self, pebble: u64, count: u32) -> u64 {
+        // Without blinking the pebble stays as it is
+        if count == 0 {
+            return 1;
+        }
+        if let Some(pebble_count) = self.cache.get(&(pebble, count)) {
+            return pebble_count;
+        }
+
+        let pebble_count = change_stone(pebble)
+            .iter()
+            .map(|p| self.blink_pebble(*p, count - 1))
+            .sum();
+
+        self.cache.insert((pebble, count), pebble_count)
+    }
 }
 
 impl PlutonianPebbles {
-    fn blink(&self, count: u32) -> u64 {
-        self.pebbles.iter().map(|p| blink_pebble(*p, count)).sum()
+    pub fn blink(&self, count: u32) -> u64 {
+        let mut counter = PebbleCounter::default();
+        let pebble_count = self
+            .pebbles
+            .iter()
+            .map(|p| counter.blink_pebble(*p, count))
+            .sum();
+
+        debug!("blink cache: {}", counter.cache.stats());
+        pebble_count
     }
 }
 
-fn parse_input(input: &str) -> PlutonianPebbles {
+pub fn parse_input(input: &str) -> Result<PlutonianPebbles, SolveError> {
     let pebbles = input
-        .trim()
-        .split(' ')
-        .map(|s| s.parse::<u64>().unwrap())
-        .collect();
-    PlutonianPebbles { pebbles }
+        .split_whitespace()
+        .map(|s| parse_number::<u64>(input, s))
+        .collect::<Result<_, _>>()?;
+    Ok(PlutonianPebbles { pebbles })
 }
 
 impl Solution for Day11 {
-    fn solve_part1(&self, input: &str) -> String {
-        let plutonian_pebbles = parse_input(&input);
-        plutonian_pebbles.blink(25).to_string()
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let plutonian_pebbles = parse_input(input)?;
+        Ok(plutonian_pebbles.blink(self.params.part1_blinks).into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let plutonian_pebbles = parse_input(&input);
-        plutonian_pebbles.blink(75).to_string()
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let plutonian_pebbles = parse_input(input)?;
+        Ok(plutonian_pebbles.blink(self.params.part2_blinks).into())
     }
 }
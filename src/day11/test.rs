@@ -1,8 +1,13 @@
-use crate::day11::Day11;
+use crate::day11::{Day11, Day11Params};
 use crate::solution::Solution;
 
 #[test]
-fn test_part1() {
-    let day = Day11 {};
-    assert_eq!(day.solve_part1("125 17"), "55312");
+fn test_without_blinking() {
+    let day = Day11 {
+        params: Day11Params {
+            part1_blinks: 0,
+            ..Default::default()
+        },
+    };
+    assert_eq!(day.solve_part1("125 17").unwrap(), "2");
 }
@@ -0,0 +1,23 @@
+use crate::generate::render_grid;
+use crate::utils::random::Rng;
+
+// A `size` by `size` garden, plots tend to take the plant of a neighbor so regions grow
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut grid = vec![vec!['A'; size]; size];
+
+    for row in 0..size {
+        for col in 0..size {
+            let roll = rng.float();
+            grid[row][col] = if roll < 0.4 && col > 0 {
+                grid[row][col - 1]
+            } else if roll < 0.8 && row > 0 {
+                grid[row - 1][col]
+            } else {
+                char::from(b'A' + rng.range(0..=25u8))
+            };
+        }
+    }
+
+    render_grid(&grid)
+}
@@ -1,27 +1,33 @@
-use crate::solution::Solution;
-use crate::utils::geometry::Direction;
-use crate::utils::nalgebra::{MatrixParser, VectorHelpers};
-use nalgebra::{DMatrix, Vector2};
-use std::collections::{HashMap, HashSet, VecDeque};
-use strum::IntoEnumIterator;
-
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
+use crate::utils::render::{self, Canvas};
+use crate::utils::search;
+use log::debug;
+use nalgebra::Vector2;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+pub mod generate;
+#[cfg(test)]
+mod oracle;
 #[cfg(test)]
 mod test;
 pub struct Day12 {}
 
-struct Plot {
-    plant: char,
-    positions: Vec<Vector2<i32>>,
-    area: usize,
-    perimeter: usize,
+pub struct Plot {
+    pub plant: char,
+    pub positions: Vec<Vector2<i32>>,
+    pub area: usize,
+    pub perimeter: usize,
 }
 
-fn array_boundaries(values: &Vec<i32>) -> (Vec<i32>, Vec<i32>) {
+fn array_boundaries(values: &[i32]) -> (Vec<i32>, Vec<i32>) {
     if values.is_empty() {
         return (vec![], vec![]);
     }
 
-    let mut v = values.clone();
+    let mut v = values.to_vec();
     v.sort();
 
     let mut left = vec![];
@@ -39,12 +45,12 @@ fn array_boundaries(values: &Vec<i32>) -> (Vec<i32>, Vec<i32>) {
     (left, right)
 }
 
-fn count_consecutive_values(values: &Vec<i32>) -> usize {
+fn count_consecutive_values(values: &[i32]) -> usize {
     array_boundaries(values).0.len()
 }
 
 impl Plot {
-    fn count_sides(&self) -> usize {
+    pub fn count_sides(&self) -> usize {
         let y_min = self.positions.iter().map(|p| p.y).min().unwrap();
         let y_max = self.positions.iter().map(|p| p.y).max().unwrap();
 
@@ -62,10 +68,10 @@ impl Plot {
 
             left_fences
                 .iter()
-                .for_each(|x| left_slices.entry(*x).or_insert_with(Vec::new).push(y));
+                .for_each(|x| left_slices.entry(*x).or_default().push(y));
             right_fences
                 .iter()
-                .for_each(|x| right_slices.entry(*x).or_insert_with(Vec::new).push(y));
+                .for_each(|x| right_slices.entry(*x).or_default().push(y));
         }
 
         let left_fences: usize = left_slices
@@ -82,95 +88,53 @@ impl Plot {
     }
 }
 
-struct Garden {
-    plants: DMatrix<char>,
+pub struct Garden {
+    plants: Grid<char>,
 }
 
 impl Garden {
-    fn within_bounds(&self, l: &Vector2<i32>) -> bool {
-        l.x >= 0 && l.x < self.plants.ncols() as i32 && l.y >= 0 && l.y < self.plants.nrows() as i32
-    }
-
     fn get_neighbors(&self, location: &Vector2<i32>) -> Vec<Vector2<i32>> {
-        Direction::iter()
-            .map(|d| location + d.to_vector())
-            .filter(|l| self.within_bounds(l))
+        self.plants
+            .neighbors4(location)
             .collect::<Vec<Vector2<i32>>>()
     }
 
-    fn explore_plot(
-        &self,
-        start: Vector2<i32>,
-        visited: &mut HashSet<Vector2<i32>>,
-    ) -> (Plot, Vec<Vector2<i32>>) {
-        let mut current_plot_queue: VecDeque<Vector2<i32>> = VecDeque::new();
-        let mut next_plot_starters: Vec<Vector2<i32>> = Vec::new();
-        let mut plot = Plot {
-            plant: self.plants[start.to_matrix_index()],
-            positions: Vec::new(),
-            area: 0,
-            perimeter: 0,
+    fn explore_plot(&self, start: Vector2<i32>) -> Plot {
+        let plant = self.plants[start];
+        let same_plot_neighbors = |location: &Vector2<i32>| {
+            self.get_neighbors(location)
+                .into_iter()
+                .filter(|n| self.plants[*n] == plant)
+                .collect::<Vec<Vector2<i32>>>()
         };
 
-        current_plot_queue.push_back(start);
-
-        while !current_plot_queue.is_empty() {
-            let current = current_plot_queue.pop_front().unwrap();
-            if visited.contains(&current) {
-                continue;
-            }
-            visited.insert(current);
-            plot.positions.push(current);
+        let region = search::bfs(start, same_plot_neighbors, |_| false);
+        let positions: Vec<Vector2<i32>> = region.distances.into_keys().collect();
 
-            let plant = self.plants[current.to_matrix_index()];
-            let neighbors = self.get_neighbors(&current);
+        let perimeter = positions
+            .iter()
+            .map(|p| 4 - same_plot_neighbors(p).len())
+            .sum();
 
-            let same_plot_neighbor_count = neighbors
-                .iter()
-                .filter(|n| self.plants[n.to_matrix_index()] == plant)
-                .count();
-            let added_perimeter = 4 - same_plot_neighbor_count;
-
-            plot.perimeter += added_perimeter;
-            plot.area += 1;
-
-            for neighbor in self.get_neighbors(&current) {
-                if visited.contains(&neighbor) {
-                    continue;
-                }
-
-                let neighbor_plant = self.plants[neighbor.to_matrix_index()];
-                if neighbor_plant == plant {
-                    current_plot_queue.push_front(neighbor.clone());
-                } else {
-                    next_plot_starters.push(neighbor.clone());
-                }
-            }
+        Plot {
+            plant,
+            area: positions.len(),
+            perimeter,
+            positions,
         }
-
-        (plot, next_plot_starters)
     }
 
-    fn find_plots(&self) -> Vec<Plot> {
-        let mut plot_starters: VecDeque<Vector2<i32>> = VecDeque::new();
+    pub fn find_plots(&self) -> Vec<Plot> {
         let mut visited: HashSet<Vector2<i32>> = HashSet::new();
         let mut plots: Vec<Plot> = Vec::new();
 
-        let start = Vector2::new(0, 0);
-        plot_starters.push_back(start);
-
-        while !plot_starters.is_empty() {
-            let current = plot_starters.pop_front().unwrap();
-            if visited.contains(&current) {
+        for position in self.plants.positions() {
+            if visited.contains(&position) {
                 continue;
             }
 
-            let (plot, next_plot_starters) = self.explore_plot(current, &mut visited);
-
-            next_plot_starters
-                .iter()
-                .for_each(|p| plot_starters.push_back(*p));
-
+            let plot = self.explore_plot(position);
+            visited.extend(plot.positions.iter().copied());
             plots.push(plot);
         }
 
@@ -178,34 +142,51 @@ impl Garden {
     }
 }
 
-fn parse_input(input: &str) -> Garden {
-    let plants = input.to_string().to_matrix(|c| c);
+impl fmt::Display for Garden {
+    // Every plant has its own color, so neighboring regions stand apart
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let canvas = Canvas::from_grid_with(&self.plants, |&plant| {
+            (plant, Some(render::group_color(plant as usize)))
+        });
 
-    Garden { plants }
+        write!(fmt, "{}", canvas)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Garden, SolveError> {
+    let plants = Grid::parse(input, |c| c.is_ascii_alphabetic().then_some(c))?;
+
+    Ok(Garden { plants })
 }
 
 impl Solution for Day12 {
-    fn solve_part1(&self, input: &str) -> String {
-        let garden = parse_input(input);
-        garden
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let garden = parse_input(input)?;
+        debug!("\n{}", garden);
+
+        Ok(garden
             .find_plots()
             .iter()
             .map(|p| p.area * p.perimeter)
             .sum::<usize>()
-            .to_string()
+            .into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let garden = parse_input(input);
-        garden
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let garden = parse_input(input)?;
+        Ok(garden
             .find_plots()
             .iter()
             .map(|p| {
-                println!("{}: {} * {}", p.plant, p.area, p.count_sides());
+                debug!("{}: {} * {}", p.plant, p.area, p.count_sides());
 
                 p.area * p.count_sides()
             })
             .sum::<usize>()
-            .to_string()
+            .into())
     }
 }
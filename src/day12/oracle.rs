@@ -0,0 +1,23 @@
+use nalgebra::Vector2;
+use std::collections::HashSet;
+
+// Every fence piece is a plot tile with a neighbor outside of the plot. A side starts at each piece
+// without a piece facing the same way next to it.
+pub fn count_sides(positions: &[Vector2<i32>]) -> usize {
+    let plot: HashSet<Vector2<i32>> = positions.iter().copied().collect();
+    let directions = [(1, 0), (0, 1), (-1, 0), (0, -1)].map(|(x, y)| Vector2::new(x, y));
+
+    let fences: HashSet<(Vector2<i32>, Vector2<i32>)> = plot
+        .iter()
+        .flat_map(|&tile| directions.map(|direction| (tile, direction)))
+        .filter(|(tile, direction)| !plot.contains(&(tile + direction)))
+        .collect();
+
+    fences
+        .iter()
+        .filter(|(tile, direction)| {
+            let along = Vector2::new(direction.y, direction.x);
+            !fences.contains(&(tile - along, *direction))
+        })
+        .count()
+}
@@ -1,76 +1,66 @@
-use crate::day12::Day12;
-use crate::solution::Solution;
+use crate::day12::{oracle, parse_input};
+use crate::property::{assert_property, expect_eq, Shrink};
+use crate::utils::random::Rng;
 
-static SAMPLE: &str = r#"
-RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE
-"#;
+// A rectangular garden that shrinks by dropping outer rows and columns, or by changing a plant
+#[derive(Clone, Debug)]
+struct Patch(Vec<Vec<char>>);
 
-#[test]
-fn test_part1() {
-    let day = Day12 {};
-    assert_eq!(day.solve_part1(SAMPLE), "1930");
-}
+impl Shrink for Patch {
+    fn shrink(&self) -> Vec<Self> {
+        let rows = &self.0;
+        let mut candidates = vec![];
 
-#[test]
-fn test_part2() {
-    let day = Day12 {};
-    assert_eq!(day.solve_part2(SAMPLE), "1206");
-}
+        if rows.len() > 1 {
+            candidates.push(Patch(rows[1..].to_vec()));
+            candidates.push(Patch(rows[..rows.len() - 1].to_vec()));
+        }
+        if rows[0].len() > 1 {
+            candidates.push(Patch(rows.iter().map(|row| row[1..].to_vec()).collect()));
+            candidates.push(Patch(
+                rows.iter()
+                    .map(|row| row[..row.len() - 1].to_vec())
+                    .collect(),
+            ));
+        }
 
-#[test]
-fn test_part2_ab() {
-    let day = Day12 {};
-    assert_eq!(
-        day.solve_part2(
-            r#"
-        AAAAAA
-        AAABBA
-        AAABBA
-        ABBAAA
-        ABBAAA
-        AAAAAA
-        "#
-        ),
-        "368"
-    );
+        for (y, row) in rows.iter().enumerate() {
+            for (x, plant) in row.iter().enumerate() {
+                for smaller in plant.shrink() {
+                    let mut replaced = rows.clone();
+                    replaced[y][x] = smaller;
+                    candidates.push(Patch(replaced));
+                }
+            }
+        }
+
+        candidates
+    }
 }
-#[test]
-fn test_part2_e() {
-    let day = Day12 {};
-    assert_eq!(
-        day.solve_part2(
-            r#"
-        EEEEE
-        EXXXX
-        EEEEE
-        EXXXX
-        EEEEE
-        "#
-        ),
-        "236"
-    );
+
+fn patch(rng: &mut Rng) -> Patch {
+    let (width, height) = (rng.range(1..=8), rng.range(1..=8));
+    let plants = ['A', 'B', 'C'];
+
+    Patch(
+        (0..height)
+            .map(|_| (0..width).map(|_| *rng.choose(&plants)).collect())
+            .collect(),
+    )
 }
 
 #[test]
-fn test_part2_custom() {
-    let day = Day12 {};
-    assert_eq!(
-        day.solve_part2(
-            r#"
-        AAAAAA
-        ABABBA
-        AAAAAA
-        "#
-        ),
-        "192"
-    );
+fn test_count_sides_matches_oracle() {
+    assert_property(patch, |Patch(rows)| {
+        let input: String = rows
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+        let garden = parse_input(&input).map_err(|e| e.to_string())?;
+
+        for plot in garden.find_plots() {
+            expect_eq(plot.count_sides(), oracle::count_sides(&plot.positions))?;
+        }
+        Ok(())
+    });
 }
@@ -0,0 +1,31 @@
+use crate::utils::random::Rng;
+
+// `size` claw machines, most prizes can be won. Buttons are never parallel.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (a, b) = loop {
+                let a: (i64, i64) = (rng.range(10..=99), rng.range(10..=99));
+                let b: (i64, i64) = (rng.range(10..=99), rng.range(10..=99));
+                if a.0 * b.1 != a.1 * b.0 {
+                    break (a, b);
+                }
+            };
+
+            let prize = if rng.chance(0.6) {
+                let (presses_a, presses_b): (i64, i64) = (rng.range(0..=100), rng.range(0..=100));
+                (
+                    presses_a * a.0 + presses_b * b.0,
+                    presses_a * a.1 + presses_b * b.1,
+                )
+            } else {
+                (rng.range(1000..=20000), rng.range(1000..=20000))
+            };
+
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .collect()
+}
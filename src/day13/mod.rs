@@ -1,45 +1,130 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::params::{at_least, parameters, Parameters};
+use crate::solution::{Answer, Solution};
+use crate::utils::nalgebra::VectorHelpers;
+use crate::utils::parsing::{parse_number, sections};
 use nalgebra::{vector, Matrix2, Vector2};
 use regex::Regex;
 
+pub mod generate;
+#[cfg(test)]
+mod oracle;
 #[cfg(test)]
 mod test;
-pub struct Day13 {}
 
-struct ClawMachine {
-    button_a: Vector2<i64>,
-    button_b: Vector2<i64>,
-    price: Vector2<i64>,
+parameters! {
+    pub struct Day13Params {
+        part1_max_presses: i64 = 100 => at_least(0),
+        part2_offset: i64 = 10000000000000 => at_least(0),
+    }
+}
+
+#[derive(Default)]
+pub struct Day13 {
+    pub params: Day13Params,
+}
+
+pub struct ClawMachine {
+    pub button_a: Vector2<i64>,
+    pub button_b: Vector2<i64>,
+    pub price: Vector2<i64>,
+}
+
+// Returns (g, s, t) with s * a + t * b = g, where g is the greatest common divisor up to its sign
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    match b {
+        0 => (a, 1, 0),
+        _ => {
+            let (g, s, t) = extended_gcd(b, a.rem_euclid(b));
+            (g, t, s - a.div_euclid(b) * t)
+        }
+    }
+}
+
+// The steps k for which `start + k * step` lies within 0..=limit
+fn step_range(start: i128, step: i128, limit: i128) -> Option<(i128, i128)> {
+    if step == 0 {
+        return (0..=limit)
+            .contains(&start)
+            .then_some((i128::MIN, i128::MAX));
+    }
+
+    let (low, high) = match step > 0 {
+        true => (-start, limit - start),
+        false => (start - limit, start),
+    };
+    let step = step.abs();
+
+    Some((-(-low).div_euclid(step), high.div_euclid(step)))
 }
 
 impl ClawMachine {
-    fn solve_button_count(&self, max: Option<i64>) -> Option<Vector2<i64>> {
+    pub fn solve_button_count(&self, max: Option<i64>) -> Option<Vector2<i64>> {
         let matrix = Matrix2::new(
             self.button_a.x,
             self.button_b.x,
             self.button_a.y,
             self.button_b.y,
         );
+        let limit = max.unwrap_or(i64::MAX);
 
-        if let Some(inverse_matrix) = matrix.map(|v| v as f64).try_inverse() {
-            let v_float = inverse_matrix * Vector2::new(self.price.x as f64, self.price.y as f64);
-            let button_count: Vector2<i64> =
-                Vector2::new(v_float.x.round() as i64, v_float.y.round() as i64);
-            if let Some(m) = max {
-                if button_count.x > m || button_count.y > m {
-                    return None;
-                }
-            }
-
-            if matrix * button_count == self.price {
-                return Some(button_count);
-            }
+        let Some(inverse_matrix) = matrix.map(|v| v as f64).try_inverse() else {
+            return self.solve_collinear(limit);
+        };
+
+        let v_float = inverse_matrix * Vector2::new(self.price.x as f64, self.price.y as f64);
+        let button_count: Vector2<i64> =
+            Vector2::new(v_float.x.round() as i64, v_float.y.round() as i64);
+        if !button_count.is_within(&vector![0, 0], &vector![limit, limit]) {
+            return None;
         }
 
-        None
+        (matrix * button_count == self.price).then_some(button_count)
     }
 
-    fn tokens_for_price(&self, max: Option<i64>) -> Option<Vector2<i64>> {
+    // Buttons that move in the same direction leave a single equation along one axis. Its
+    // solutions are evenly spaced and their cost changes linearly, so the cheapest one presses
+    // the buttons as close to one end of the allowed range as possible.
+    fn solve_collinear(&self, limit: i64) -> Option<Vector2<i64>> {
+        let axis = match self.button_a.x == 0 && self.button_b.x == 0 {
+            true => 1,
+            false => 0,
+        };
+        let [a, b, price] = [self.button_a, self.button_b, self.price].map(|v| v[axis] as i128);
+
+        let (g, s, t) = extended_gcd(a, b);
+        if g == 0 {
+            // Buttons that do not move only reach a prize at the claw
+            return (self.price == vector![0, 0]).then_some(vector![0, 0]);
+        }
+        if price % g != 0 {
+            return None;
+        }
+
+        // Every solution is (a_start + k * a_step, b_start + k * b_step)
+        let (a_start, b_start) = (s * (price / g), t * (price / g));
+        let (a_step, b_step) = (b / g, -a / g);
+
+        let (a_low, a_high) = step_range(a_start, a_step, limit as i128)?;
+        let (b_low, b_high) = step_range(b_start, b_step, limit as i128)?;
+        let (low, high) = (a_low.max(b_low), a_high.min(b_high));
+        if low > high {
+            return None;
+        }
+
+        let k = match 3 * a_step + b_step > 0 {
+            true => low,
+            false => high,
+        };
+        let count = vector![a_start + k * a_step, b_start + k * b_step];
+
+        // The prize also has to lie on the line the buttons move along
+        let reached =
+            self.button_a.map(|v| v as i128) * count.x + self.button_b.map(|v| v as i128) * count.y;
+        (reached == self.price.map(|v| v as i128)).then(|| count.map(|v| v as i64))
+    }
+
+    pub fn tokens_for_price(&self, max: Option<i64>) -> Option<Vector2<i64>> {
         if let Some(count) = self.solve_button_count(max) {
             return Some(Vector2::new(count.x * 3, count.y));
         }
@@ -48,45 +133,63 @@ impl ClawMachine {
     }
 }
 
-static CLAW_DESCRIPTION_REGEX: &str = r"\Button A: X\+(\d+), Y\+(\d+)\r?\n\s*Button B: X\+(\d+), Y\+(\d+)\r?\n\s*Prize: X=(\d+), Y=(\d+)";
-fn parse_input(input: &str) -> Vec<ClawMachine> {
+static CLAW_DESCRIPTION_REGEX: &str =
+    r"^Button A: X\+(\d+), Y\+(\d+)\s*Button B: X\+(\d+), Y\+(\d+)\s*Prize: X=(\d+), Y=(\d+)$";
+pub fn parse_input(input: &str) -> Result<Vec<ClawMachine>, SolveError> {
     let re = Regex::new(CLAW_DESCRIPTION_REGEX).unwrap();
 
-    re.captures_iter(input.trim())
-        .map(|c| c.extract())
-        .map(|(_, [a_x, a_y, b_x, b_y, p_x, p_y])| ClawMachine {
-            button_a: vector![a_x.parse::<i64>().unwrap(), a_y.parse::<i64>().unwrap()],
-            button_b: vector![b_x.parse::<i64>().unwrap(), b_y.parse::<i64>().unwrap()],
-            price: vector![p_x.parse::<i64>().unwrap(), p_y.parse::<i64>().unwrap()],
+    sections(input)
+        .into_iter()
+        .map(|section| {
+            let (_, [a_x, a_y, b_x, b_y, p_x, p_y]) = re
+                .captures(section)
+                .ok_or_else(|| {
+                    SolveError::invalid_input(input, section, "expected a claw machine description")
+                })?
+                .extract();
+
+            Ok(ClawMachine {
+                button_a: vector![parse_number(input, a_x)?, parse_number(input, a_y)?],
+                button_b: vector![parse_number(input, b_x)?, parse_number(input, b_y)?],
+                price: vector![parse_number(input, p_x)?, parse_number(input, p_y)?],
+            })
         })
         .collect()
 }
 
 impl Solution for Day13 {
-    fn solve_part1(&self, input: &str) -> String {
-        let machines = parse_input(input);
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let machines = parse_input(input)?;
 
-        machines
+        Ok(machines
             .iter()
-            .filter_map(|machine| machine.tokens_for_price(Some(100)))
+            .filter_map(|machine| machine.tokens_for_price(Some(self.params.part1_max_presses)))
             .map(|v| v.sum())
             .sum::<i64>()
-            .to_string()
+            .into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let machines = parse_input(input);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let machines = parse_input(input)?;
 
-        machines
+        Ok(machines
             .iter()
             .map(|m| ClawMachine {
                 button_a: m.button_a,
                 button_b: m.button_b,
-                price: m.price + vector![10000000000000, 10000000000000],
+                price: m.price + Vector2::repeat(self.params.part2_offset),
             })
             .filter_map(|machine| machine.tokens_for_price(None))
             .map(|v| v.sum())
             .sum::<i64>()
-            .to_string()
+            .into())
     }
 }
@@ -0,0 +1,10 @@
+use crate::day13::ClawMachine;
+
+// Tries every combination of presses up to `max`, returning the fewest tokens
+pub fn fewest_tokens(machine: &ClawMachine, max: i64) -> Option<i64> {
+    (0..=max)
+        .flat_map(|a| (0..=max).map(move |b| (a, b)))
+        .filter(|&(a, b)| machine.button_a * a + machine.button_b * b == machine.price)
+        .map(|(a, b)| 3 * a + b)
+        .min()
+}
@@ -1,35 +1,69 @@
-use crate::day13::Day13;
+use crate::day13::{oracle, ClawMachine, Day13};
+use crate::error::SolveError;
+use crate::property::{assert_property, expect_eq};
 use crate::solution::Solution;
+use crate::utils::random::Rng;
+use nalgebra::Vector2;
 
 #[test]
-fn test_part1() {
-    let day = Day13 {};
-    assert_eq!(
-        day.solve_part1(
-            r#"
-        Button A: X+94, Y+34
-        Button B: X+22, Y+67
-        Prize: X=8400, Y=5400
-        
-        Button A: X+26, Y+66
-        Button B: X+67, Y+21
-        Prize: X=12748, Y=12176
-        
-        Button A: X+17, Y+86
-        Button B: X+84, Y+37
-        Prize: X=7870, Y=6450
-        
-        Button A: X+69, Y+23
-        Button B: X+27, Y+71
-        Prize: X=18641, Y=10279
-        "#
+fn test_invalid_machine() {
+    let day = Day13::default();
+    let error = day
+        .solve_part1("Button A: X+94, Y+34\nButton B: X+22\nPrize: X=8400, Y=5400")
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        SolveError::InvalidInput {
+            line: 1,
+            column: 1,
+            ..
+        }
+    ));
+}
+
+type Machine = ((i64, i64), (i64, i64), (i64, i64));
+
+// Prizes are mostly placed at a number of presses, which can be negative to check that such prizes
+// are rejected. Some machines have buttons that move in the same direction.
+fn machine(rng: &mut Rng) -> (Machine, i64) {
+    let button = |rng: &mut Rng| (rng.range(1..=10), rng.range(1..=10));
+    let (a, b) = match rng.chance(0.3) {
+        true => {
+            let direction = button(rng);
+            let (m, n) = (rng.range(1..=4), rng.range(1..=4));
+            (
+                (direction.0 * m, direction.1 * m),
+                (direction.0 * n, direction.1 * n),
+            )
+        }
+        false => (button(rng), button(rng)),
+    };
+    let (presses_a, presses_b) = (rng.range(-4..=12), rng.range(-4..=12));
+    let price = match rng.chance(0.8) {
+        true => (
+            presses_a * a.0 + presses_b * b.0,
+            presses_a * a.1 + presses_b * b.1,
         ),
-        "480"
-    );
+        false => (rng.range(0..=100), rng.range(0..=100)),
+    };
+
+    ((a, b, price), rng.range(0..=15))
 }
 
 #[test]
-fn test_part2() {
-    let day = Day13 {};
-    assert_eq!(day.solve_part2(""), "");
+fn test_tokens_match_oracle() {
+    assert_property(machine, |&((a, b, price), max)| {
+        let machine = ClawMachine {
+            button_a: Vector2::new(a.0, a.1),
+            button_b: Vector2::new(b.0, b.1),
+            price: Vector2::new(price.0, price.1),
+        };
+
+        expect_eq(
+            machine
+                .tokens_for_price(Some(max))
+                .map(|tokens| tokens.sum()),
+            oracle::fewest_tokens(&machine, max),
+        )
+    });
 }
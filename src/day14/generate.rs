@@ -0,0 +1,42 @@
+use crate::utils::random::Rng;
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+// `size` robots in the puzzle room. About half of them form a filled triangle at a random moment,
+// their starting positions are walked back from there.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let height = ((size / 2) as f64).sqrt().clamp(1.0, 40.0) as i32;
+    let moment = rng.range(1..=WIDTH * HEIGHT - 1);
+    let (top, center) = (
+        rng.range(0..=HEIGHT - height),
+        rng.range(height..=WIDTH - height),
+    );
+
+    let mut robots: Vec<((i32, i32), (i32, i32))> = (0..height)
+        .flat_map(|row| (-row..=row).map(move |offset| (center + offset, top + row)))
+        .map(|(x, y)| {
+            let velocity = (rng.range(-99..=99), rng.range(-99..=99));
+            let x = (x - moment * velocity.0).rem_euclid(WIDTH);
+            let y = (y - moment * velocity.1).rem_euclid(HEIGHT);
+            ((x, y), velocity)
+        })
+        .collect();
+
+    // The room size is derived from the furthest robots, so the first two are put at the far sides
+    for i in 0.. {
+        let position = match i {
+            0 => (WIDTH - 1, rng.range(0..=HEIGHT - 1)),
+            1 => (rng.range(0..=WIDTH - 1), HEIGHT - 1),
+            _ if robots.len() < size => (rng.range(0..=WIDTH - 1), rng.range(0..=HEIGHT - 1)),
+            _ => break,
+        };
+        robots.push((position, (rng.range(-99..=99), rng.range(-99..=99))));
+    }
+    rng.shuffle(&mut robots);
+
+    robots
+        .iter()
+        .map(|((x, y), (vx, vy))| format!("p={},{} v={},{}\n", x, y, vx, vy))
+        .collect()
+}
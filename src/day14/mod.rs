@@ -1,42 +1,60 @@
-use crate::solution::Solution;
-use crate::utils::geometry::Direction;
+use crate::error::SolveError;
+use crate::params::{at_least, auto_or, finite, parameters, Parameters};
+use crate::solution::{Answer, Solution};
+use crate::utils::nalgebra::VectorHelpers;
+use crate::utils::parsing::{non_empty_lines, parse_number};
+use crate::utils::render::{Frame, Image, Recorder, BLACK, GREEN};
+use log::info;
 use nalgebra::{vector, Vector2};
 use regex::Regex;
 use std::fmt;
-use strum::IntoEnumIterator;
 
+pub mod generate;
 #[cfg(test)]
 mod test;
-pub struct Day14 {}
+
+parameters! {
+    pub struct Day14Params {
+        // The room size is derived from the robot positions when not given
+        width: Option<i32> = None => auto_or(at_least(1)),
+        height: Option<i32> = None => auto_or(at_least(1)),
+        steps: usize = 100,
+        tree_threshold: f32 = 0.2 => finite,
+    }
+}
+
+#[derive(Default)]
+pub struct Day14 {
+    pub params: Day14Params,
+}
 
 #[derive(Clone, PartialEq)]
-struct RobotMovement {
-    position: Vector2<i32>,
-    velocity: Vector2<i32>,
+pub struct RobotMovement {
+    pub position: Vector2<i32>,
+    pub velocity: Vector2<i32>,
 }
 
 impl RobotMovement {
-    fn walk(&self, steps: usize, room_size: Vector2<i32>) -> Vector2<i32> {
-        let p: Vector2<i32> = self.position + steps as i32 * self.velocity;
-        let mut iter = p
-            .iter()
-            .zip(room_size.iter())
-            .map(|(x, max)| x.rem_euclid(*max));
+    pub fn walk(&self, steps: usize, room_size: Vector2<i32>) -> Vector2<i32> {
+        // A robot is back on its row or column after as many steps as the room is wide or high
+        let walk = |position: i32, velocity: i32, max: i32| {
+            let steps = (steps % max as usize) as i64;
+            (position as i64 + steps * velocity as i64).rem_euclid(max as i64) as i32
+        };
 
-        Vector2::new(iter.next().unwrap(), iter.next().unwrap())
+        vector![
+            walk(self.position.x, self.velocity.x, room_size.x),
+            walk(self.position.y, self.velocity.y, room_size.y)
+        ]
     }
 
     fn get_neighbors(&self) -> Vec<Vector2<i32>> {
-        let neighbors = Direction::iter()
-            .map(|d| self.position + d.to_vector())
-            .collect::<Vec<Vector2<i32>>>();
-
-        neighbors
+        self.position.neighbors4().to_vec()
     }
 }
 
 #[derive(Clone, PartialEq)]
-struct RobotField {
+pub struct RobotField {
     robots: Vec<RobotMovement>,
     size: Vector2<i32>,
 }
@@ -68,13 +86,22 @@ impl RobotField {
         q1 * q2 * q3 * q4
     }
 
-    fn compute_safety_factor(&self, steps: usize) -> usize {
+    pub fn moved(&self, steps: usize) -> Self {
         let mut moved_field = self.clone();
         for robot in moved_field.robots.iter_mut() {
             robot.position = robot.walk(steps, self.size);
         }
 
-        moved_field.count_robots_in_quadrants()
+        moved_field
+    }
+
+    pub fn compute_safety_factor(&self, steps: usize) -> usize {
+        self.moved(steps).count_robots_in_quadrants()
+    }
+
+    pub fn to_image(&self) -> Image {
+        let image = Image::new(self.size.x as usize, self.size.y as usize, BLACK);
+        image.with_overlay(self.robots.iter().map(|r| r.position), GREEN)
     }
 
     fn count_boxed_in_robots(&self) -> usize {
@@ -88,7 +115,7 @@ impl RobotField {
             .count()
     }
 
-    fn find_christmas_tree(&self, boxed_in_threshold: f32) -> Option<usize> {
+    pub fn find_christmas_tree(&self, boxed_in_threshold: f32) -> Option<usize> {
         let mut moved_field = self.clone();
 
         let mut walk_counter = 0;
@@ -104,14 +131,14 @@ impl RobotField {
             let boxed_in_percentage = boxed_in_robots as f32 / moved_field.robots.len() as f32;
 
             if boxed_in_percentage > boxed_in_threshold {
-                println!(
+                info!(
                     "boxed in robots: {}/{} ({:.1}%) walk_counter: {}",
                     boxed_in_robots,
                     moved_field.robots.len(),
                     boxed_in_percentage * 100.0,
                     walk_counter
                 );
-                println!("{}", moved_field);
+                info!("\n{}", moved_field);
 
                 return Some(walk_counter);
             }
@@ -140,47 +167,118 @@ impl fmt::Display for RobotField {
                     write!(fmt, "{}", count)?;
                 }
             }
-            write!(fmt, "\n")?;
+            writeln!(fmt)?;
         }
 
         Ok(())
     }
 }
 
-static ROBOT_MOVEMENT_REGEX: &str = r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)";
-fn parse_input(input: &str) -> RobotField {
+static ROBOT_MOVEMENT_REGEX: &str = r"^p=(\d+),(\d+) v=(-?\d+),(-?\d+)$";
+pub fn parse_input(input: &str) -> Result<RobotField, SolveError> {
     let re = Regex::new(ROBOT_MOVEMENT_REGEX).unwrap();
 
-    let movements: Vec<RobotMovement> = re
-        .captures_iter(input.trim())
-        .map(|c| c.extract())
-        .map(|(_, [p_x, p_y, v_x, v_y])| RobotMovement {
-            position: vector![p_x.parse::<i32>().unwrap(), p_y.parse::<i32>().unwrap()],
-            velocity: vector![v_x.parse::<i32>().unwrap(), v_y.parse::<i32>().unwrap()],
+    let movements = non_empty_lines(input)
+        .map(|line| {
+            let (_, [p_x, p_y, v_x, v_y]) = re
+                .captures(line)
+                .ok_or_else(|| SolveError::invalid_input(input, line, "expected 'p=x,y v=x,y'"))?
+                .extract();
+
+            Ok(RobotMovement {
+                position: vector![parse_number(input, p_x)?, parse_number(input, p_y)?],
+                velocity: vector![parse_number(input, v_x)?, parse_number(input, v_y)?],
+            })
         })
-        .collect();
+        .collect::<Result<Vec<RobotMovement>, SolveError>>()?;
+
+    if movements.is_empty() {
+        return Err(SolveError::unexpected_end(input, "expected at least one robot"));
+    }
 
     let max_x = movements.iter().map(|m| m.position.x).max().unwrap() + 1;
     let max_y = movements.iter().map(|m| m.position.y).max().unwrap() + 1;
 
-    RobotField {
+    Ok(RobotField {
         robots: movements,
         size: vector![max_x, max_y],
+    })
+}
+
+impl Day14 {
+    fn parse_field(&self, input: &str) -> Result<RobotField, SolveError> {
+        let mut field = parse_input(input)?;
+        if let Some(width) = self.params.width {
+            field.size.x = width;
+        }
+        if let Some(height) = self.params.height {
+            field.size.y = height;
+        }
+        if field.size.x <= 0 || field.size.y <= 0 {
+            return Err(SolveError::no_solution(format!(
+                "the room of {}x{} tiles has no space for the robots",
+                field.size.x, field.size.y
+            )));
+        }
+        Ok(field)
     }
 }
 
 impl Solution for Day14 {
-    fn solve_part1(&self, input: &str) -> String {
-        let field = parse_input(input);
-        field.compute_safety_factor(100).to_string()
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        self.parse_field(input).map(|_| ())
+    }
+
+    // The room at the step the robots form the christmas tree
+    fn render(&self, input: &str) -> Option<Result<Image, SolveError>> {
+        let render = || {
+            let field = self.parse_field(input)?;
+            let steps = field
+                .find_christmas_tree(self.params.tree_threshold)
+                .ok_or_else(|| SolveError::no_solution("the robots never form a christmas tree"))?;
+
+            Ok(field.moved(steps).to_image())
+        };
+
+        Some(render())
+    }
+
+    // The robots moving for the steps of part 1, one frame per second
+    fn record(&self, input: &str, recorder: &mut Recorder) -> Option<Result<(), SolveError>> {
+        let record = |recorder: &mut Recorder| {
+            let mut field = self.parse_field(input)?;
+            recorder.push(|| Frame::new(field.to_image(), field.to_string()));
+
+            for _ in 0..self.params.steps {
+                if recorder.is_full() {
+                    break;
+                }
+
+                field = field.moved(1);
+                recorder.push(|| Frame::new(field.to_image(), field.to_string()));
+            }
+
+            Ok(())
+        };
+
+        Some(record(recorder))
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let field = self.parse_field(input)?;
+        Ok(field.compute_safety_factor(self.params.steps).into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let field = parse_input(input);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let field = self.parse_field(input)?;
 
-        field
-            .find_christmas_tree(0.2)
+        Ok(field
+            .find_christmas_tree(self.params.tree_threshold)
             .unwrap_or_default()
-            .to_string()
+            .into())
     }
 }
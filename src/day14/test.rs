@@ -1,32 +1,33 @@
-use crate::day14::Day14;
+use crate::day14::{Day14, Day14Params, RobotMovement};
 use crate::solution::Solution;
+use nalgebra::vector;
 
 #[test]
-fn test_part1() {
-    let day = Day14 {};
-    assert_eq!(
-        day.solve_part1(
-            r#"
-        p=0,4 v=3,-3
-        p=6,3 v=-1,-3
-        p=10,3 v=-1,2
-        p=2,0 v=2,-1
-        p=0,0 v=1,3
-        p=3,0 v=-2,-2
-        p=7,6 v=-1,-3
-        p=3,0 v=-1,-2
-        p=9,3 v=2,3
-        p=7,3 v=-1,2
-        p=2,4 v=2,-3
-        p=9,5 v=-3,-3
-        "#
-        ),
-        "12"
-    );
+fn test_part2() {
+    let day = Day14::default();
+    assert!(day.solve_part2("").is_err());
 }
 
 #[test]
-fn test_part2() {
-    let day = Day14 {};
-    assert_eq!(day.solve_part2(""), "");
+fn test_empty_room() {
+    let day = Day14 {
+        params: Day14Params {
+            width: Some(0),
+            ..Default::default()
+        },
+    };
+    assert!(day.solve_part1("p=0,4 v=3,-3").is_err());
+}
+
+#[test]
+fn test_walk_many_steps() {
+    let robot = RobotMovement {
+        position: vector![2, 4],
+        velocity: vector![2, -3],
+    };
+    let room = vector![11, 7];
+
+    assert_eq!(robot.walk(5, room), vector![1, 3]);
+    // The room is back in its starting state every 77 steps
+    assert_eq!(robot.walk(5 + 77 * 1_000_000_000_000, room), vector![1, 3]);
 }
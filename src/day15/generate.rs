@@ -0,0 +1,39 @@
+use crate::generate::render_grid;
+use crate::utils::random::Rng;
+
+// A `size` by `size` warehouse followed by `size` lines of moves
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(4);
+    let mut grid: Vec<Vec<char>> = (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|col| {
+                    let border = row == 0 || col == 0 || row == size - 1 || col == size - 1;
+                    let roll = rng.float();
+                    if border || roll < 0.05 {
+                        '#'
+                    } else if roll < 0.25 {
+                        'O'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let row = rng.range(1..=size - 2);
+    let col = rng.range(1..=size - 2);
+    grid[row][col] = '@';
+
+    let moves: String = (0..size)
+        .map(|_| {
+            (0..70)
+                .map(|_| *rng.choose(&['<', '>', '^', 'v']))
+                .collect::<String>()
+                + "\n"
+        })
+        .collect();
+
+    render_grid(&grid) + "\n" + &moves
+}
@@ -1,16 +1,19 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
+use crate::utils::parsing::expect_sections;
 use crate::utils::geometry::Direction;
-use crate::utils::nalgebra::{MatrixHelpers, MatrixParser, VectorHelpers};
-use nalgebra::{vector, DMatrix, Vector2};
+use crate::utils::grid::{Grid, Tile};
+use crate::utils::nalgebra::Markers;
+use crate::utils::render::{self, Color, Colored, Frame, Recorder};
+use nalgebra::Vector2;
 use std::collections::VecDeque;
 use std::fmt;
 
-#[cfg(test)]
-mod test;
+pub mod generate;
 pub struct Day15 {}
 
 #[derive(Clone, Debug, PartialEq)]
-enum WarehouseTile {
+pub enum WarehouseTile {
     Wall,
     Box,
     Floor,
@@ -18,34 +21,43 @@ enum WarehouseTile {
     BoxRight,
     Robot,
 }
-impl fmt::Display for WarehouseTile {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+impl Tile for WarehouseTile {
+    fn to_char(&self) -> char {
         match self {
-            WarehouseTile::Wall => fmt.write_str("#"),
-            WarehouseTile::Box => fmt.write_str("O"),
-            WarehouseTile::Floor => fmt.write_str("."),
-            WarehouseTile::Robot => fmt.write_str("@"),
-            WarehouseTile::BoxLeft => fmt.write_str("["),
-            WarehouseTile::BoxRight => fmt.write_str("]"),
+            WarehouseTile::Wall => '#',
+            WarehouseTile::Box => 'O',
+            WarehouseTile::Floor => '.',
+            WarehouseTile::Robot => '@',
+            WarehouseTile::BoxLeft => '[',
+            WarehouseTile::BoxRight => ']',
         }
     }
 }
 
-struct Warehouse {
-    tiles: DMatrix<WarehouseTile>,
+impl Colored for WarehouseTile {
+    fn color(&self) -> Color {
+        match self {
+            WarehouseTile::Wall => render::GRAY,
+            WarehouseTile::Box | WarehouseTile::BoxLeft | WarehouseTile::BoxRight => render::GOLD,
+            WarehouseTile::Floor => render::WHITE,
+            WarehouseTile::Robot => render::RED,
+        }
+    }
+}
+
+pub struct Warehouse {
+    tiles: Grid<WarehouseTile>,
     robot_position: Vector2<i32>,
     remaining_moves: VecDeque<Direction>,
 }
 
 impl Warehouse {
-    fn box_positions(&self) -> Vec<Vector2<i32>> {
-        (0..self.tiles.ncols())
-            .flat_map(|x| (0..self.tiles.nrows()).map(move |y| vector![x as i32, y as i32]))
-            .filter(|v: &Vector2<i32>| {
-                *self.tiles.at(v) == WarehouseTile::Box
-                    || *self.tiles.at(v) == WarehouseTile::BoxLeft
-            })
-            .collect::<Vec<Vector2<i32>>>()
+    pub fn box_positions(&self) -> Vec<Vector2<i32>> {
+        self.tiles
+            .iter()
+            .filter(|(_, t)| **t == WarehouseTile::Box || **t == WarehouseTile::BoxLeft)
+            .map(|(p, _)| p)
+            .collect()
     }
 
     fn move_to_two_sided(
@@ -67,10 +79,8 @@ impl Warehouse {
             } else {
                 return false;
             }
-        } else {
-            if !self.move_into(to, direction, apply) {
-                return false;
-            }
+        } else if !self.move_into(to, direction, apply) {
+            return false;
         }
 
         true
@@ -78,7 +88,7 @@ impl Warehouse {
 
     fn move_into(&mut self, from: Vector2<i32>, direction: &Direction, apply: bool) -> bool {
         let to = from + direction.to_vector();
-        match self.tiles.at(&to) {
+        match self.tiles[to] {
             WarehouseTile::Wall => {
                 return false;
             }
@@ -104,124 +114,142 @@ impl Warehouse {
         }
 
         if apply {
-            self.tiles[to.to_matrix_index()] = self.tiles[from.to_matrix_index()].clone();
-            self.tiles[from.to_matrix_index()] = WarehouseTile::Floor;
+            self.tiles[to] = self.tiles[from].clone();
+            self.tiles[from] = WarehouseTile::Floor;
         }
 
         true
     }
 
-    fn robot_step(&mut self, direction: &Direction) -> bool {
+    pub fn robot_step(&mut self, direction: &Direction) -> bool {
         if self.move_into(self.robot_position, direction, true) {
-            self.robot_position = self.robot_position + direction.to_vector();
+            self.robot_position += direction.to_vector();
             return true;
         }
 
         false
     }
 
-    fn robot_walk(&mut self) {
+    pub fn robot_walk(&mut self) {
+        self.robot_walk_recorded(&mut Recorder::disabled());
+    }
+
+    // Walks the robot like `robot_walk`, pushing a frame before the first and after every move
+    pub fn robot_walk_recorded(&mut self, recorder: &mut Recorder) {
+        recorder.push(|| self.frame());
+
         while let Some(direction) = self.remaining_moves.pop_front() {
             self.robot_step(&direction);
-
-            // println!("Direction: {:?}", direction);
-            // println!("{}", self)
+            recorder.push(|| self.frame());
         }
     }
+
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.tiles)
+    }
 }
 
 impl fmt::Display for Warehouse {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        for row in 0..self.tiles.nrows() {
-            for col in 0..self.tiles.ncols() {
-                let tile = self.tiles.get((row, col)).unwrap();
-                write!(fmt, "{}", tile)?;
-            }
-            write!(fmt, "\n")?;
-        }
+        write!(fmt, "{}", self.tiles)
+    }
+}
 
-        Ok(())
+fn parse_tile(c: char) -> Option<WarehouseTile> {
+    match c {
+        '#' => Some(WarehouseTile::Wall),
+        'O' => Some(WarehouseTile::Box),
+        '.' => Some(WarehouseTile::Floor),
+        '@' => Some(WarehouseTile::Robot),
+        '[' => Some(WarehouseTile::BoxLeft),
+        ']' => Some(WarehouseTile::BoxRight),
+        _ => None,
     }
 }
 
-fn parse_warehouse(input: &str, expand: bool) -> DMatrix<WarehouseTile> {
-    let mut input_processed: String = input.into();
-    if expand {
-        input_processed = input_processed.replace("#", "##");
-        input_processed = input_processed.replace("O", "[]");
-        input_processed = input_processed.replace(".", "..");
-        input_processed = input_processed.replace("@", "@.");
-    }
-
-    let tiles = input_processed.to_matrix(|c| match c {
-        '#' => WarehouseTile::Wall,
-        'O' => WarehouseTile::Box,
-        '.' => WarehouseTile::Floor,
-        '@' => WarehouseTile::Robot,
-        '[' => WarehouseTile::BoxLeft,
-        ']' => WarehouseTile::BoxRight,
-        _ => panic!("Could not parse warehouse tile: {}", c),
-    });
-
-    tiles
+fn parse_warehouse(
+    input: &str,
+    raw_warehouse: &str,
+    expand: bool,
+) -> Result<(Grid<WarehouseTile>, Markers), SolveError> {
+    // Validate the original layout first, so errors point at the input rather than the expansion
+    let parsed = Grid::parse_with_markers(raw_warehouse, &['@'], parse_tile)
+        .map_err(|e| e.within(input, raw_warehouse))?;
+
+    if !expand {
+        return Ok(parsed);
+    }
+
+    let expanded = raw_warehouse
+        .replace("#", "##")
+        .replace("O", "[]")
+        .replace(".", "..")
+        .replace("@", "@.");
+    Grid::parse_with_markers(&expanded, &['@'], parse_tile)
 }
 
-fn parse_moves(input: &str) -> VecDeque<Direction> {
-    input
-        .chars()
-        .filter(|&c| c != '\n' && c != '\r')
-        .map(|c| match c {
-            '>' => Direction::Right,
-            '<' => Direction::Left,
-            '^' => Direction::Up,
-            'v' => Direction::Down,
-            _ => panic!("Could not parse move: {}", c),
+fn parse_moves(input: &str, raw_moves: &str) -> Result<VecDeque<Direction>, SolveError> {
+    raw_moves
+        .char_indices()
+        .filter(|&(_, c)| !c.is_whitespace())
+        .map(|(offset, c)| match c {
+            '>' => Ok(Direction::Right),
+            '<' => Ok(Direction::Left),
+            '^' => Ok(Direction::Up),
+            'v' => Ok(Direction::Down),
+            _ => Err(SolveError::invalid_input(
+                input,
+                &raw_moves[offset..],
+                format!("could not parse move '{}'", c),
+            )),
         })
         .collect()
 }
 
-fn parse_input(input: &str, expand: bool) -> Warehouse {
-    let normalized = input.replace("\r\n", "\n");
-    let lines: Vec<_> = normalized.lines().map(|l| l.trim()).collect();
-    let processed_input = lines.join("\n");
-    let mut parts = processed_input.split("\n\n");
+pub fn parse_input(input: &str, expand: bool) -> Result<Warehouse, SolveError> {
+    let [raw_warehouse, raw_robot_movement] = expect_sections(input)?;
 
-    let raw_warehouse = parts.next().unwrap().trim();
-    let raw_robot_movement = parts.next().unwrap().trim();
+    let (warehouse, markers) = parse_warehouse(input, raw_warehouse, expand)?;
+    let pos = markers.first('@').ok_or_else(|| {
+        SolveError::invalid_input(input, raw_warehouse, "the warehouse does not contain a robot")
+    })?;
 
-    let warehouse = parse_warehouse(raw_warehouse, expand);
-    let pos = (0..warehouse.ncols())
-        .flat_map(|x| (0..warehouse.nrows()).map(move |y| vector![x as i32, y as i32]))
-        .find(|v: &Vector2<i32>| *warehouse.at(v) == WarehouseTile::Robot)
-        .unwrap();
-
-    Warehouse {
+    Ok(Warehouse {
         tiles: warehouse,
         robot_position: pos,
-        remaining_moves: parse_moves(raw_robot_movement),
-    }
+        remaining_moves: parse_moves(input, raw_robot_movement)?,
+    })
 }
 
 impl Solution for Day15 {
-    fn solve_part1(&self, input: &str) -> String {
-        let mut warehouse = parse_input(input, false);
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input, false).map(|_| ())
+    }
+
+    // The robot pushing boxes through the wide warehouse of part 2
+    fn record(&self, input: &str, recorder: &mut Recorder) -> Option<Result<(), SolveError>> {
+        Some(parse_input(input, true).map(|mut warehouse| warehouse.robot_walk_recorded(recorder)))
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let mut warehouse = parse_input(input, false)?;
         warehouse.robot_walk();
-        warehouse
+        Ok(warehouse
             .box_positions()
             .iter()
             .map(|p| 100 * p.y + p.x)
             .sum::<i32>()
-            .to_string()
+            .into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let mut warehouse = parse_input(input, true);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let mut warehouse = parse_input(input, true)?;
         warehouse.robot_walk();
-        warehouse
+        Ok(warehouse
             .box_positions()
             .iter()
             .map(|p| 100 * p.y + p.x)
             .sum::<i32>()
-            .to_string()
+            .into())
     }
 }
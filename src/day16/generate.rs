@@ -0,0 +1,22 @@
+use crate::generate::{maze, odd_side, render_grid};
+use crate::utils::random::Rng;
+
+// A maze of about `size` by `size` with some walls knocked out, so there are several paths
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = odd_side(size);
+    let mut grid = maze(rng, side);
+
+    for (row, line) in grid.iter_mut().enumerate().take(side - 1).skip(1) {
+        for (col, tile) in line.iter_mut().enumerate().take(side - 1).skip(1) {
+            // Walls between two cells, either horizontally or vertically
+            let between = (row % 2 == 1) != (col % 2 == 1);
+            if between && *tile == '#' && rng.chance(0.1) {
+                *tile = '.';
+            }
+        }
+    }
+
+    grid[side - 2][1] = 'S';
+    grid[1][side - 2] = 'E';
+    render_grid(&grid)
+}
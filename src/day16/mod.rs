@@ -1,18 +1,23 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
 use crate::utils::geometry::Direction;
-use crate::utils::nalgebra::{MatrixHelpers, MatrixParser, VectorHelpers};
-use nalgebra::{DMatrix, Vector2};
-use priority_queue::PriorityQueue;
-use std::cmp::{min, Reverse};
-use std::collections::{HashMap, HashSet, VecDeque};
+use crate::utils::grid::{Grid, Tile};
+use crate::utils::nalgebra::VectorHelpers;
+use crate::utils::render::{self, Color, Colored, Image};
+use crate::utils::search::{self, Search};
+use nalgebra::Vector2;
+use std::collections::{HashMap, HashSet};
 use strum::IntoEnumIterator;
 
+pub mod generate;
 #[cfg(test)]
 mod test;
 pub struct Day16 {}
 
-struct Maze {
-    tiles: DMatrix<MazeTile>,
+pub struct Maze {
+    tiles: Grid<MazeTile>,
+    start: Vector2<i32>,
+    end: Vector2<i32>,
 }
 
 #[derive(Clone, Hash, PartialEq, Eq)]
@@ -29,20 +34,50 @@ enum MazeTile {
     Floor,
 }
 
+impl Tile for MazeTile {
+    fn to_char(&self) -> char {
+        match self {
+            MazeTile::Start => 'S',
+            MazeTile::End => 'E',
+            MazeTile::Wall => '#',
+            MazeTile::Floor => '.',
+        }
+    }
+}
+
+impl Colored for MazeTile {
+    fn color(&self) -> Color {
+        match self {
+            MazeTile::Start => render::GREEN,
+            MazeTile::End => render::RED,
+            MazeTile::Wall => render::GRAY,
+            MazeTile::Floor => render::WHITE,
+        }
+    }
+}
+
 impl Maze {
+    // Tiles outside of the maze are walls, so mazes without a border cannot be left
+    fn is_open(&self, position: &Vector2<i32>) -> bool {
+        self.tiles
+            .get(position)
+            .is_some_and(|tile| *tile != MazeTile::Wall)
+    }
+
     fn walk_to_neighbors(&self, node: &MazeNode) -> HashMap<MazeNode, u32> {
         let mut position = node.position + node.direction.to_vector();
         let mut neighbors = HashMap::new();
         let mut steps = 1;
 
-        while self.tiles.at_value(&position) != MazeTile::Wall {
+        while self.is_open(&position) {
             let sides = Direction::iter()
                 .filter(|d| node.direction.is_vertical() != d.is_vertical())
                 .map(|d| position + d.to_vector())
-                .filter(|p| self.tiles.at_value(p) != MazeTile::Wall)
+                .filter(|p| self.is_open(p))
                 .count();
 
-            if sides > 0 {
+            // The end can also lie in the middle of a corridor
+            if sides > 0 || position == self.end {
                 neighbors.insert(
                     MazeNode {
                         position,
@@ -52,7 +87,7 @@ impl Maze {
                 );
             }
 
-            position = position + node.direction.to_vector();
+            position += node.direction.to_vector();
             steps += 1;
         }
 
@@ -62,7 +97,7 @@ impl Maze {
     fn find_neighbors(&self, node: &MazeNode) -> HashMap<MazeNode, u32> {
         let mut neighbors: HashMap<MazeNode, u32> = HashMap::new();
 
-        let forward_neighbors = self.walk_to_neighbors(&node);
+        let forward_neighbors = self.walk_to_neighbors(node);
         neighbors.extend(forward_neighbors);
 
         let right_neighbors = self.walk_to_neighbors(&MazeNode {
@@ -90,130 +125,94 @@ impl Maze {
         neighbors
     }
 
-    fn shortest_path(&self) -> (u32, HashMap<MazeNode, Vec<MazeNode>>, MazeNode) {
-        let start = self.tiles.find_index(MazeTile::Start).unwrap();
-        let end = self.tiles.find_index(MazeTile::End).unwrap();
+    fn shortest_path(&self) -> Result<Search<MazeNode>, SolveError> {
+        let start = MazeNode {
+            position: self.start,
+            direction: Direction::Right,
+        };
 
-        let mut distances: HashMap<MazeNode, u32> = HashMap::new();
-        let mut pred: HashMap<MazeNode, Vec<MazeNode>> = HashMap::new();
-
-        let mut queue: PriorityQueue<MazeNode, _> = PriorityQueue::new();
-        queue.push(
-            MazeNode {
-                position: start,
-                direction: Direction::Right,
-            },
-            Reverse(0),
+        let search = search::dijkstra(
+            start,
+            |node| self.find_neighbors(node),
+            |node| node.position == self.end,
         );
 
-        while !queue.is_empty() {
-            let (node, distance) = queue.pop().unwrap();
-            let distance = distance.0;
-
-            distances.insert(node.clone(), distance);
-
-            if node.position == end {
-                break;
-            }
-
-            let neighbors = self.find_neighbors(&node);
-            for (neighbor, neighbor_distance) in neighbors {
-                if distances.contains_key(&neighbor) {
-                    continue;
-                }
-
-                if queue.iter().any(|f| *f.0 == neighbor) {
-                    let current_distance = queue.get_priority(&neighbor).unwrap().0;
-                    let new_distance = distance + neighbor_distance;
-                    if new_distance < current_distance {
-                        pred.insert(neighbor.clone(), vec![node.clone()]);
-                    } else if new_distance == current_distance {
-                        pred.get_mut(&neighbor.clone()).unwrap().push(node.clone());
-                    }
-
-                    let new_priority = min(current_distance, new_distance);
-                    // println!("Updating priority of {:?} (direction: {:?}) from {} to {}", neighbor.position, neighbor.direction, current_distance, new_priority);
-                    queue.change_priority(&neighbor, Reverse(new_priority));
-                } else {
-                    // println!("Adding {:?} (direction: {:?}) at distance {:?}", neighbor.position, neighbor.direction, distance + neighbor_distance);
-                    pred.insert(neighbor.clone(), vec![node.clone()]);
-                    queue.push(neighbor, Reverse(distance + neighbor_distance));
-                }
-            }
+        match search.target() {
+            Some(_) => Ok(search),
+            None => Err(SolveError::no_solution("the end tile cannot be reached")),
         }
-
-        let (end_node, distance) = distances
-            .iter()
-            .filter(|(n, _)| self.tiles.at_value(&n.position) == MazeTile::End)
-            .min_by_key(|(_, &d)| d)
-            .unwrap();
-
-        (*distance, pred, end_node.clone())
     }
 
-    fn shortest_path_length(&self) -> u32 {
-        self.shortest_path().0
+    pub fn shortest_path_length(&self) -> Result<u32, SolveError> {
+        Ok(self.shortest_path()?.target_distance().unwrap())
     }
 
-    fn observer_wall_count(&self) -> u32 {
-        let (_, predecessors, end_node) = self.shortest_path();
-
-        let mut best_path_points: HashSet<Vector2<i32>> = HashSet::new();
-
-        let mut visited: HashSet<Vector2<i32>> = HashSet::new();
-        let mut remaining: VecDeque<MazeNode> = VecDeque::new();
-        remaining.push_front(end_node.clone());
-
-        while !remaining.is_empty() {
-            let current = remaining.pop_front().unwrap();
-            visited.insert(current.position);
-
-            if !predecessors.contains_key(&current) {
-                continue;
-            }
+    // The tiles on any of the best paths through the maze
+    fn best_path_points(&self) -> Result<HashSet<Vector2<i32>>, SolveError> {
+        Ok(self
+            .shortest_path()?
+            .optimal_edges()
+            .into_iter()
+            .flat_map(|(from, to)| from.position.get_intermediate_points(&to.position))
+            .collect())
+    }
 
-            if let Some(predecessors) = predecessors.get(&current) {
-                // println!("predecessors of {:?}: {:?}", current.position, predecessors.iter().map(|e| e.position).collect::<Vec<_>>());
-
-                for predecessor in predecessors {
-                    if visited.contains(&predecessor.position) {
-                        continue;
-                    }
-                    remaining.push_back(predecessor.clone());
-
-                    best_path_points.extend(
-                        current
-                            .position
-                            .get_intermediate_points(&predecessor.position),
-                    );
-                }
-            }
-        }
+    pub fn observer_wall_count(&self) -> Result<u32, SolveError> {
+        Ok(self.best_path_points()?.len() as u32)
+    }
 
-        best_path_points.iter().count() as u32
+    pub fn to_image(&self) -> Result<Image, SolveError> {
+        // Start and end are drawn last so the paths do not hide them
+        Ok(Image::from_grid(&self.tiles, MazeTile::color)
+            .with_overlay(self.best_path_points()?, render::GOLD)
+            .with_overlay([self.start], MazeTile::Start.color())
+            .with_overlay([self.end], MazeTile::End.color()))
     }
 }
 
-fn parse_input(input: &str) -> Maze {
-    Maze {
-        tiles: input.to_string().to_matrix(|c| match c {
-            'S' => MazeTile::Start,
-            'E' => MazeTile::End,
-            '#' => MazeTile::Wall,
-            '.' => MazeTile::Floor,
-            _ => panic!("Unexpected character in input: {}", c),
-        }),
-    }
+pub fn parse_input(input: &str) -> Result<Maze, SolveError> {
+    let (tiles, markers) = Grid::parse_with_markers(input, &['S', 'E'], |c| match c {
+        'S' => Some(MazeTile::Start),
+        'E' => Some(MazeTile::End),
+        '#' => Some(MazeTile::Wall),
+        '.' => Some(MazeTile::Floor),
+        _ => None,
+    })?;
+
+    let [start, end] = [('S', "start"), ('E', "end")].map(|(marker, name)| {
+        markers.first(marker).ok_or_else(|| {
+            SolveError::invalid_input(
+                input,
+                input.trim(),
+                format!("the maze has no {} tile", name),
+            )
+        })
+    });
+
+    Ok(Maze {
+        tiles,
+        start: start?,
+        end: end?,
+    })
 }
 
 impl Solution for Day16 {
-    fn solve_part1(&self, input: &str) -> String {
-        let maze = parse_input(input);
-        maze.shortest_path_length().to_string()
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    // The maze with the tiles of the best paths highlighted
+    fn render(&self, input: &str) -> Option<Result<Image, SolveError>> {
+        Some(parse_input(input).and_then(|maze| maze.to_image()))
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let maze = parse_input(input)?;
+        Ok(maze.shortest_path_length()?.into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let maze = parse_input(input);
-        maze.observer_wall_count().to_string()
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let maze = parse_input(input)?;
+        Ok(maze.observer_wall_count()?.into())
     }
 }
@@ -1,5 +1,7 @@
 use crate::day16::Day16;
 use crate::solution::Solution;
+use crate::utils::render::{GOLD, GREEN, RED};
+use nalgebra::vector;
 
 static SAMPLE: &str = r#"
 ###############
@@ -20,41 +22,24 @@ static SAMPLE: &str = r#"
 "#;
 
 #[test]
-fn test_part1() {
+fn test_render() {
     let day = Day16 {};
-    assert_eq!(day.solve_part1(SAMPLE), "7036");
-}
+    let image = day.render(SAMPLE).unwrap().unwrap();
 
-#[test]
-fn test_part2_first() {
-    let day = Day16 {};
-    assert_eq!(day.solve_part2(SAMPLE), "45");
+    assert_eq!((image.width(), image.height()), (15, 15));
+    assert_eq!(image.get(&vector![1, 13]), Some(GREEN));
+    assert_eq!(image.get(&vector![13, 1]), Some(RED));
+
+    let positions = (0..15).flat_map(|y| (0..15).map(move |x| vector![x, y]));
+    let best_tiles = positions.filter(|p| image.get(p) == Some(GOLD)).count();
+    // The 45 tiles of the best paths, without start and end
+    assert_eq!(best_tiles, 43);
 }
 
 #[test]
-fn test_part2_second() {
+fn test_maze_without_border() {
     let day = Day16 {};
-    assert_eq!(
-        day.solve_part2(
-            r#"
-        #################
-        #...#...#...#..E#
-        #.#.#.#.#.#.#.#.#
-        #.#.#.#...#...#.#
-        #.#.#.#.###.#.#.#
-        #...#.#.#.....#.#
-        #.#.#.#.#.#####.#
-        #.#...#.#.#.....#
-        #.#.#####.#.###.#
-        #.#.#.......#...#
-        #.#.###.#####.###
-        #.#.#...#.....#.#
-        #.#.#.#####.###.#
-        #.#.#.........#.#
-        #.#.#.#########.#
-        #S#.............#
-        #################"#
-        ),
-        "64"
-    );
+    assert_eq!(day.solve_part1("S.E").unwrap(), "2");
+    assert_eq!(day.solve_part2("S.E").unwrap(), "3");
+    assert!(day.solve_part1("S#E").is_err());
 }
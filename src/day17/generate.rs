@@ -0,0 +1,55 @@
+use crate::day17::computer::{Computer, Registers};
+use crate::day17::instruction::Instruction;
+use crate::day17::parser::ComputerParser;
+use crate::utils::random::Rng;
+
+fn run(instructions: &[Instruction], a: i64) -> Vec<i64> {
+    let mut computer = Computer::new(Registers { a, b: 0, c: 0 });
+    computer.execute(instructions);
+    computer.output
+}
+
+// Builds the value of register A three bits at a time, starting from the last output
+fn find_quine(instructions: &[Instruction], program: &[i64], a: i64, index: usize) -> bool {
+    (0..8).any(|bits| {
+        let candidate = a * 8 + bits;
+        candidate != 0
+            && run(instructions, candidate) == program[index..]
+            && (index == 0 || find_quine(instructions, program, candidate, index - 1))
+    })
+}
+
+// A program in the shape of the puzzle, which shifts register A by three bits each round until it
+// is zero. Register A starts with `size` rounds, and some value of A makes the program output
+// itself.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let rounds = size.clamp(1, 20) as u32;
+
+    loop {
+        let mut mixing = [vec![1, rng.range(0..=7)], vec![4, rng.range(0..=7)]];
+        rng.shuffle(&mut mixing);
+
+        let program: Vec<i64> = [vec![2, 4, 1, rng.range(0..=7), 7, 5]]
+            .into_iter()
+            .chain(mixing)
+            .chain([vec![0, 3, 5, 5, 3, 0]])
+            .flatten()
+            .collect();
+
+        let input = format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            rng.range(8i64.pow(rounds - 1)..=8i64.pow(rounds) - 1),
+            program
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+
+        let (_, instructions, _) =
+            ComputerParser::parse(&input).expect("generated programs are valid");
+        if find_quine(&instructions, &program, 0, program.len() - 1) {
+            return input;
+        }
+    }
+}
@@ -1,33 +1,32 @@
 use crate::day17::computer::Computer;
 use crate::day17::parser::ComputerParser;
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
 
-mod computer;
-mod instruction;
-mod operation;
-mod parser;
-#[cfg(test)]
-mod test;
+pub mod computer;
+pub mod generate;
+pub mod instruction;
+pub mod operation;
+pub mod parser;
 
 pub struct Day17 {}
 
 impl Solution for Day17 {
-    fn solve_part1(&self, input: &str) -> String {
-        let (registers, instructions, _) = ComputerParser::parse(input);
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        ComputerParser::parse(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let (registers, instructions, _) = ComputerParser::parse(input)?;
         let mut computer = Computer::new(registers);
 
         computer.execute(&instructions);
 
-        computer
-            .output
-            .iter()
-            .map(|v| v.to_string())
-            .collect::<Vec<_>>()
-            .join(",")
+        Ok(computer.output.iter().copied().collect())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let (registers, instructions, raw_instructions) = ComputerParser::parse(input);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let (registers, instructions, raw_instructions) = ComputerParser::parse(input)?;
 
         // This works, but it is probably more elegant to do this using a SAT solver.
 
@@ -59,6 +58,6 @@ impl Solution for Day17 {
             }
         }
 
-        start_a.to_string()
+        Ok(start_a.into())
     }
 }
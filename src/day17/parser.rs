@@ -1,13 +1,15 @@
 use crate::day17::computer::Registers;
 use crate::day17::instruction::Instruction;
 use crate::day17::operation::*;
+use crate::error::SolveError;
+use crate::utils::parsing::{non_empty_lines, parse_number};
 use regex::Regex;
 
 pub struct ComputerParser;
 
 impl ComputerParser {
-    fn build_operation(opcode: i64) -> Box<dyn Operation> {
-        match opcode {
+    fn build_operation(opcode: i64) -> Option<Box<dyn Operation>> {
+        let operation: Box<dyn Operation> = match opcode {
             0 => Box::new(OperationADV),
             1 => Box::new(OperationBXL),
             2 => Box::new(OperationBST),
@@ -16,54 +18,89 @@ impl ComputerParser {
             5 => Box::new(OperationOUT),
             6 => Box::new(OperationBDV),
             7 => Box::new(OperationCDV),
-            _ => panic!("Invalid operation code: {}", opcode),
-        }
+            _ => return None,
+        };
+
+        Some(operation)
     }
 
-    pub(crate) fn parse(input: &str) -> (Registers, Vec<Instruction>, Vec<i64>) {
-        let register_re = Regex::new(r"Register (\w):\s*(-?\d+)").unwrap();
-        let program_re = Regex::new(r"Program:\s*(.*)").unwrap();
+    pub fn parse(
+        input: &str,
+    ) -> Result<(Registers, Vec<Instruction>, Vec<i64>), SolveError> {
+        let register_re = Regex::new(r"^Register (\w):\s*(-?\d+)$").unwrap();
+        let program_re = Regex::new(r"^Program:\s*(.*)$").unwrap();
 
         let mut registers = Registers { a: 0, b: 0, c: 0 };
         let mut instructions = Vec::new();
         let mut raw_instructions = Vec::new();
 
-        for line in input.lines() {
-            let line = line.trim();
-
+        for line in non_empty_lines(input) {
             // Match register lines
             if let Some(caps) = register_re.captures(line) {
-                let register = &caps[1];
-                let value = caps[2].parse::<i64>().unwrap_or(0);
+                let (_, [register, value]) = caps.extract();
+                let value = parse_number::<i64>(input, value)?;
 
                 match register {
                     "A" => registers.a = value,
                     "B" => registers.b = value,
                     "C" => registers.c = value,
-                    _ => {}
+                    _ => {
+                        return Err(SolveError::invalid_input(
+                            input,
+                            register,
+                            format!("unknown register '{}'", register),
+                        ))
+                    }
                 }
+
+                continue;
             }
 
             // Match program line and parse pairs
             if let Some(caps) = program_re.captures(line) {
-                let program_values: Vec<i64> = caps[1]
+                let (_, [program]) = caps.extract();
+                let program_values = program
                     .split(',')
-                    .filter_map(|s| s.trim().parse().ok())
-                    .collect();
+                    .map(|s| parse_number::<i64>(input, s).map(|v| (s, v)))
+                    .collect::<Result<Vec<_>, SolveError>>()?;
+
+                if !program_values.len().is_multiple_of(2) {
+                    return Err(SolveError::invalid_input(
+                        input,
+                        program,
+                        "expected pairs of opcodes and operands",
+                    ));
+                }
 
                 // Parse values as pairs: (operation, operand)
                 for pair in program_values.chunks(2) {
-                    instructions.push(Instruction {
-                        operation: Self::build_operation(pair[0]),
-                        operand: pair[1],
-                    });
+                    let (raw_opcode, opcode) = pair[0];
+                    let (_, operand) = pair[1];
+
+                    let operation = Self::build_operation(opcode).ok_or_else(|| {
+                        SolveError::invalid_input(
+                            input,
+                            raw_opcode.trim(),
+                            format!("invalid operation code {}", opcode),
+                        )
+                    })?;
+
+                    instructions.push(Instruction { operation, operand });
 
-                    raw_instructions.push(pair[0]);
-                    raw_instructions.push(pair[1]);
+                    raw_instructions.push(opcode);
+                    raw_instructions.push(operand);
                 }
+
+                continue;
             }
+
+            return Err(SolveError::invalid_input(
+                input,
+                line,
+                format!("expected a register or program, found '{}'", line),
+            ));
         }
 
-        (registers, instructions, raw_instructions)
+        Ok((registers, instructions, raw_instructions))
     }
 }
@@ -0,0 +1,77 @@
+use crate::utils::random::Rng;
+use std::collections::VecDeque;
+
+const SIDE: usize = 71;
+// The bytes part 1 lets fall
+const FALLEN: usize = 1024;
+
+fn has_path(blocked: &[Vec<bool>]) -> bool {
+    let mut seen = vec![vec![false; SIDE]; SIDE];
+    let mut queue = VecDeque::from([(0, 0)]);
+    seen[0][0] = true;
+
+    while let Some((x, y)) = queue.pop_front() {
+        if (x, y) == (SIDE - 1, SIDE - 1) {
+            return true;
+        }
+
+        for (dx, dy) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
+            let (Some(nx), Some(ny)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+                continue;
+            };
+            if nx < SIDE && ny < SIDE && !blocked[ny][nx] && !seen[ny][nx] {
+                seen[ny][nx] = true;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+
+    false
+}
+
+// `size` bytes falling into the 71 by 71 memory space of the puzzle, at least one more than the
+// first kilobyte. The exit stays reachable after the first kilobyte and the last byte cuts it off,
+// unless a byte before it already did.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(FALLEN + 1);
+    let exit = (SIDE - 1, SIDE - 1);
+
+    // The first bytes fall into the last column and row, so the memory space has its full size
+    let mut positions = vec![
+        (SIDE - 1, rng.range(0..=SIDE - 3)),
+        (rng.range(0..=SIDE - 3), SIDE - 1),
+    ];
+    let mut rest: Vec<(usize, usize)> = (0..SIDE)
+        .flat_map(|y| (0..SIDE).map(move |x| (x, y)))
+        .filter(|p| *p != (0, 0) && *p != exit && !positions.contains(p))
+        .collect();
+    rng.shuffle(&mut rest);
+    positions.extend(rest);
+    // A byte on the exit cuts it off when no other byte can
+    positions.push(exit);
+
+    let mut blocked = vec![vec![false; SIDE]; SIDE];
+    let mut cut = false;
+    let mut bytes = vec![];
+
+    for (x, y) in positions {
+        if bytes.len() == size {
+            break;
+        }
+
+        blocked[y][x] = true;
+        if !cut {
+            // Part 1 needs the exit reachable, part 2 needs the last byte to cut it off at the latest
+            let path = has_path(&blocked);
+            if (!path && bytes.len() < FALLEN) || (path && bytes.len() == size - 1) {
+                blocked[y][x] = false;
+                continue;
+            }
+            cut = !path;
+        }
+
+        bytes.push(format!("{},{}\n", x, y));
+    }
+
+    bytes.concat()
+}
@@ -1,76 +1,123 @@
-use crate::solution::Solution;
-use crate::utils::geometry::Direction;
+use crate::error::SolveError;
+use crate::params::{parameters, Parameters};
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::{Grid, Tile};
 use crate::utils::nalgebra::VectorHelpers;
-use nalgebra::{vector, DMatrix, Vector2};
-use priority_queue::PriorityQueue;
-use std::cmp::Reverse;
-use std::collections::HashMap;
+use crate::utils::parsing::{non_empty_lines, parse_number, split_pair};
+use crate::utils::render::{self, Canvas, Color, Colored, Frame, Recorder};
+use crate::utils::search;
+use log::debug;
+use nalgebra::{vector, Vector2};
 use std::fmt;
-use strum::IntoEnumIterator;
 
+pub mod generate;
 #[cfg(test)]
 mod test;
+
+parameters! {
+    pub struct Day18Params {
+        // The real puzzle asks for the path after the first kilobyte has fallen
+        bytes: usize = 1024,
+    }
+}
+
+#[derive(Default)]
 pub struct Day18 {
-    pub max_byte_count: Option<usize>,
+    pub params: Day18Params,
 }
 
-type Position = Vector2<i32>;
+pub type Position = Vector2<i32>;
 
 #[derive(Debug, Clone, PartialEq)]
-enum MemoryTile {
+pub enum MemoryTile {
     EMPTY,
     BYTE,
 }
 
-impl fmt::Display for MemoryTile {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+impl Tile for MemoryTile {
+    fn to_char(&self) -> char {
+        match self {
+            MemoryTile::EMPTY => '.',
+            MemoryTile::BYTE => '#',
+        }
+    }
+}
+
+impl Colored for MemoryTile {
+    fn color(&self) -> Color {
         match self {
-            MemoryTile::EMPTY => fmt.write_str("."),
-            MemoryTile::BYTE => fmt.write_str("#"),
+            MemoryTile::EMPTY => render::WHITE,
+            MemoryTile::BYTE => render::GRAY,
         }
     }
 }
 
 #[derive(Debug, Clone)]
-struct MemorySpace {
-    field: DMatrix<MemoryTile>,
+pub struct MemorySpace {
+    field: Grid<MemoryTile>,
 }
 
 #[derive(Debug, Clone)]
-struct MemorySpacePath {
+pub struct MemorySpacePath {
     space: MemorySpace,
-    path: Option<Vec<Vector2<i32>>>,
+    pub path: Option<Vec<Vector2<i32>>>,
 }
 
-struct FallingBytes {
+pub struct FallingBytes {
     bytes: Vec<Position>,
 }
 
 impl FallingBytes {
-    fn new(input: &str) -> Self {
-        let bytes = input
-            .trim()
-            .lines()
-            .map(|line| line.trim())
-            .map(|line| line.split_once(",").unwrap())
-            .map(|(x, y)| (x.parse::<i32>().unwrap(), y.parse::<i32>().unwrap()))
-            .map(|(x, y)| vector![x, y])
-            .collect::<Vec<Position>>();
+    pub fn new(input: &str) -> Result<Self, SolveError> {
+        // Coordinates are parsed unsigned, as bytes cannot fall outside the memory space, and
+        // must fit the signed positions of the grid
+        let coordinate = |token: &str| {
+            i32::try_from(parse_number::<u32>(input, token)?).map_err(|_| {
+                SolveError::invalid_input(input, token.trim(), "the coordinate is too large")
+            })
+        };
+
+        let bytes = non_empty_lines(input)
+            .map(|line| {
+                let (x, y) = split_pair(input, line, ",")?;
+                Ok(vector![coordinate(x)?, coordinate(y)?])
+            })
+            .collect::<Result<Vec<Position>, SolveError>>()?;
+
+        if bytes.is_empty() {
+            return Err(SolveError::unexpected_end(input, "expected at least one byte"));
+        }
 
-        Self { bytes }
+        Ok(Self { bytes })
     }
 
-    fn has_path(&self, byte_count: usize) -> bool {
+    pub fn has_path(&self, byte_count: usize) -> bool {
         let ram = MemorySpace::new(self, Some(byte_count));
         ram.find_path().path.is_some()
     }
 
-    fn find_blocking_byte(&self) -> Option<Position> {
+    // Lets the bytes fall one after the other, pushing a frame for the empty memory space and
+    // after every byte
+    pub fn record(&self, recorder: &mut Recorder) {
+        let mut space = MemorySpace::new(self, Some(0));
+        recorder.push(|| Frame::from_grid(&space.field));
+
+        for byte in &self.bytes {
+            if recorder.is_full() {
+                break;
+            }
+
+            space.field[*byte] = MemoryTile::BYTE;
+            recorder.push(|| Frame::from_grid(&space.field));
+        }
+    }
+
+    pub fn find_blocking_byte(&self) -> Option<Position> {
         let mut left: usize = 0;
         let mut right = self.bytes.len();
-        
+
         if self.has_path(right) {
-            return None
+            return None;
         }
 
         while left < right {
@@ -83,88 +130,50 @@ impl FallingBytes {
             }
         }
 
-        Some(self.bytes[left].clone())
+        Some(self.bytes[left])
     }
 }
 
 impl MemorySpace {
-    fn new(falling_bytes: &FallingBytes, take: Option<usize>) -> Self {
+    pub fn new(falling_bytes: &FallingBytes, take: Option<usize>) -> Self {
         let bytes = falling_bytes
             .bytes
             .iter()
             .take(take.unwrap_or(usize::MAX))
             .collect::<Vec<_>>();
 
-        let max_x = bytes.iter().map(|v| v.x + 1).max().unwrap() as usize;
-        let max_y = bytes.iter().map(|v| v.y + 1).max().unwrap() as usize;
+        // The memory space is sized by all bytes, including those that have not fallen yet
+        let max_x = falling_bytes.bytes.iter().map(|v| v.x + 1).max().unwrap() as usize;
+        let max_y = falling_bytes.bytes.iter().map(|v| v.y + 1).max().unwrap() as usize;
 
-        let mut field = DMatrix::from_element(max_y, max_x, MemoryTile::EMPTY);
+        let mut field = Grid::new(max_x, max_y, MemoryTile::EMPTY);
 
         for byte in bytes {
-            field[byte.to_matrix_index()] = MemoryTile::BYTE;
+            field[*byte] = MemoryTile::BYTE;
         }
 
         Self { field }
     }
 
-    fn within_bounds(&self, p: &Position) -> bool {
-        p.x >= 0 && p.x < self.field.ncols() as i32 && p.y >= 0 && p.y < self.field.nrows() as i32
-    }
-
     fn neighbors(&self, pos: &Position) -> Vec<Position> {
-        Direction::iter()
-            .map(|d| pos + d.to_vector())
-            .filter(|p| self.within_bounds(p))
-            .filter(|p| self.field[p.to_matrix_index()] != MemoryTile::BYTE)
+        self.field
+            .neighbors4(pos)
+            .filter(|p| self.field[*p] != MemoryTile::BYTE)
             .collect()
     }
 
     fn heuristic(position: &Position, target: &Position) -> u32 {
-        (position - target).abs().sum() as u32
-    }
-
-    fn reconstruct_path(
-        predecessors: &HashMap<Position, Position>,
-        end: Position,
-    ) -> Vec<Position> {
-        let mut current = end;
-        let mut path = vec![current];
-        while predecessors.contains_key(&current) {
-            current = predecessors[&current];
-            path.push(current);
-        }
-
-        path.into_iter().rev().collect()
+        position.manhattan_distance(target) as u32
     }
 
     fn a_star(&self, start: Position, target: Position) -> Option<Vec<Position>> {
-        let mut open_set: PriorityQueue<Position, Reverse<u32>> = PriorityQueue::new();
-        let mut predecessor: HashMap<Position, Position> = HashMap::new();
-
-        let mut g_score: HashMap<Position, u32> = HashMap::new();
-        g_score.insert(start, 0);
-
-        open_set.push(start, Reverse(Self::heuristic(&start, &target)));
-
-        while !open_set.is_empty() {
-            let (current_position, _) = open_set.pop().unwrap();
-            let current_score = *g_score.get(&current_position).unwrap_or(&u32::MAX);
-
-            if current_position == target {
-                return Some(Self::reconstruct_path(&predecessor, target));
-            }
-
-            for neighbor in self.neighbors(&current_position) {
-                let score = current_score + 1;
-                if score < *g_score.get(&neighbor).unwrap_or(&u32::MAX) {
-                    predecessor.insert(neighbor, current_position);
-                    g_score.insert(neighbor, score);
-                    open_set.push(neighbor, Reverse(score + Self::heuristic(&start, &target)));
-                }
-            }
-        }
-
-        None
+        search::a_star(
+            start,
+            |p| self.neighbors(p).into_iter().map(|n| (n, 1)),
+            |p| Self::heuristic(p, &target),
+            |p| *p == target,
+        )
+        .path()
     }
 
     fn top_left(&self) -> Position {
@@ -172,12 +181,12 @@ impl MemorySpace {
     }
     fn bottom_right(&self) -> Position {
         vector![
-            (self.field.ncols() - 1) as i32,
-            (self.field.nrows() - 1) as i32
+            (self.field.width() - 1) as i32,
+            (self.field.height() - 1) as i32
         ]
     }
 
-    fn find_path(&self) -> MemorySpacePath {
+    pub fn find_path(&self) -> MemorySpacePath {
         MemorySpacePath {
             space: self.clone(),
             path: self.a_star(self.top_left(), self.bottom_right()),
@@ -187,51 +196,57 @@ impl MemorySpace {
 
 impl fmt::Display for MemorySpace {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        for row in 0..self.field.nrows() {
-            for col in 0..self.field.ncols() {
-                write!(fmt, "{}", self.field[(row, col)])?;
-            }
-            write!(fmt, "\n")?;
-        }
-
-        Ok(())
+        write!(fmt, "{}", self.field)
     }
 }
 
 impl fmt::Display for MemorySpacePath {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let path = self.path.clone().unwrap_or(vec![]);
-        for row in 0..self.space.field.nrows() {
-            for col in 0..self.space.field.ncols() {
-                let v: Position = vector![col as i32, row as i32];
-                if path.contains(&v) {
-                    write!(fmt, "O")?;
-                } else {
-                    write!(fmt, "{}", self.space.field[v.to_matrix_index()])?;
-                }
-            }
-            write!(fmt, "\n")?;
-        }
+        let canvas = Canvas::from_colored_grid(&self.space.field)
+            .with_highlight(self.path.clone().unwrap_or_default(), 'O', render::GOLD, "path")
+            .with_legend('#', MemoryTile::BYTE.color(), "corrupted byte");
 
-        Ok(())
+        write!(fmt, "{}", canvas)
     }
 }
 
 impl Solution for Day18 {
-    fn solve_part1(&self, input: &str) -> String {
-        let bytes = FallingBytes::new(input);
-        let ram = MemorySpace::new(&bytes, self.max_byte_count);
-        let path = ram.find_path();
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
 
-        println!("{}", path);
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        FallingBytes::new(input).map(|_| ())
+    }
 
-        // Note: the solution does not take the first location into account
-        (path.path.unwrap().len() - 1).to_string()
+    // Every byte falling into the memory space
+    fn record(&self, input: &str, recorder: &mut Recorder) -> Option<Result<(), SolveError>> {
+        Some(FallingBytes::new(input).map(|bytes| bytes.record(recorder)))
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let bytes = FallingBytes::new(input);
-        let blocking_byte = bytes.find_blocking_byte().unwrap();
-        format!("{},{}", blocking_byte.x, blocking_byte.y)
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let bytes = FallingBytes::new(input)?;
+        let ram = MemorySpace::new(&bytes, Some(self.params.bytes));
+        let path = ram.find_path();
+
+        debug!("\n{}", path);
+
+        let steps = path
+            .path
+            .ok_or_else(|| SolveError::no_solution("the exit cannot be reached"))?;
+
+        // Note: the solution does not take the first location into account
+        Ok((steps.len() - 1).into())
+    }
+
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let bytes = FallingBytes::new(input)?;
+        let blocking_byte = bytes
+            .find_blocking_byte()
+            .ok_or_else(|| SolveError::no_solution("no byte blocks the exit"))?;
+        Ok(Answer::Coordinate(
+            blocking_byte.x as i64,
+            blocking_byte.y as i64,
+        ))
     }
 }
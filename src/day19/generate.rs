@@ -0,0 +1,38 @@
+use crate::utils::random::Rng;
+use std::collections::BTreeSet;
+
+static COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+// A set of towels followed by `size` designs, most of them made from the towels
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut towels = BTreeSet::new();
+    while towels.len() < (size / 2).clamp(5, 400) {
+        let length = rng.range(1..=8);
+        towels.insert(
+            (0..length)
+                .map(|_| *rng.choose(&COLORS))
+                .collect::<String>(),
+        );
+    }
+    let mut towels: Vec<String> = towels.into_iter().collect();
+    rng.shuffle(&mut towels);
+
+    let designs: String = (0..size)
+        .map(|_| {
+            let length = rng.range(20..=60);
+            let mut design = String::new();
+            let from_towels = rng.chance(0.7);
+
+            while design.len() < length {
+                match from_towels {
+                    true => design += rng.choose(&towels).as_str(),
+                    false => design.push(*rng.choose(&COLORS)),
+                }
+            }
+
+            design + "\n"
+        })
+        .collect();
+
+    towels.join(", ") + "\n\n" + &designs
+}
@@ -1,68 +1,114 @@
-use memoize::memoize;
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
+use crate::utils::memo::Memo;
+use crate::utils::parsing::{expect_sections, non_empty_lines};
+use log::debug;
 
+pub mod generate;
 #[cfg(test)]
 mod test;
 pub struct Day19 {}
 
-struct TowelAndPatterns {
+pub struct TowelAndPatterns {
     towels: Vec<String>,
     patterns: Vec<String>,
 }
 
+// Counts the arrangements of a single towel set, the cache must not be shared between sets
+pub struct PatternCounter<'a> {
+    towels: &'a [String],
+    cache: Memo<&'a str, usize>,
+}
+
+impl<'a> PatternCounter<'a> {
+    pub fn new(towels: &'a [String]) -> Self {
+        Self {
+            towels,
+            cache: Memo::new(),
+        }
+    }
 
-#[memoize(Ignore: towels)]
-fn count_possible_patterns(towels: &Vec<String>, pattern: String) -> usize {
-    if pattern.is_empty() { return 1 }
+    pub fn count(&mut self, pattern: &'a str) -> usize {
+        if pattern.is_empty() {
+            return 1;
+        }
+
+        if let Some(count) = self.cache.get(&pattern) {
+            return count;
+        }
+
+        let count = self
+            .towels
+            .iter()
+            .filter_map(|towel| pattern.strip_prefix(towel.as_str()))
+            .map(|remaining_pattern| self.count(remaining_pattern))
+            .sum();
 
-    towels.iter()
-        .filter_map(|towel| pattern.strip_prefix(towel))
-        .map(|remaining_pattern| count_possible_patterns(towels, remaining_pattern.into()))
-        .sum()
+        self.cache.insert(pattern, count)
+    }
 }
 
 impl TowelAndPatterns {
-    fn new(input: &str) -> Self {
-        let normalized = input.trim().replace("\r\n", "\n");
-        let (raw_towels, raw_patterns) = normalized.split_once("\n\n").unwrap();
+    pub fn new(input: &str) -> Result<Self, SolveError> {
+        let [raw_towels, raw_patterns] = expect_sections(input)?;
 
-        Self {
-            towels: raw_towels
-                .trim()
-                .split(",")
-                .map(|i| i.trim().to_string())
-                .collect(),
-            patterns: raw_patterns
-                .trim()
-                .lines()
-                .map(|i| i.trim().to_string())
+        let towels = raw_towels
+            .split(",")
+            .map(|i| i.trim())
+            .map(|towel| {
+                if towel.is_empty() {
+                    Err(SolveError::invalid_input(
+                        input,
+                        towel,
+                        "found an empty towel",
+                    ))
+                } else {
+                    Ok(towel.to_string())
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            towels,
+            patterns: non_empty_lines(raw_patterns)
+                .map(|i| i.to_string())
                 .collect(),
-        }
+        })
     }
 
-    fn count_valid_patterns(&self) -> usize {
-        self.patterns
+    pub fn count_valid_patterns(&self) -> usize {
+        let mut counter = PatternCounter::new(&self.towels);
+        let valid = self
+            .patterns
             .iter()
-            .filter(|&p| count_possible_patterns(&self.towels, p.into()) > 0)
-            .count()
+            .filter(|&p| counter.count(p) > 0)
+            .count();
+
+        debug!("pattern cache: {}", counter.cache.stats());
+        valid
     }
 
-    fn count_possible_patterns(&self) -> usize {
-        self.patterns
-            .iter()
-            .map(|p| count_possible_patterns(&self.towels, p.into()))
-            .sum()
+    pub fn count_possible_patterns(&self) -> usize {
+        let mut counter = PatternCounter::new(&self.towels);
+        let possible = self.patterns.iter().map(|p| counter.count(p)).sum();
+
+        debug!("pattern cache: {}", counter.cache.stats());
+        possible
     }
 }
 
 impl Solution for Day19 {
-    fn solve_part1(&self, input: &str) -> String {
-        let collection = TowelAndPatterns::new(input);
-        collection.count_valid_patterns().to_string()
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        TowelAndPatterns::new(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let collection = TowelAndPatterns::new(input)?;
+        Ok(collection.count_valid_patterns().into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let collection = TowelAndPatterns::new(input);
-        collection.count_possible_patterns().to_string()
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let collection = TowelAndPatterns::new(input)?;
+        Ok(collection.count_possible_patterns().into())
     }
 }
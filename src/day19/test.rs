@@ -1,7 +1,6 @@
 use crate::day19::Day19;
 use crate::solution::Solution;
 
-
 static SAMPLE: &str = r#"
 r, wr, b, g, bwu, rb, gb, br
 
@@ -15,13 +14,9 @@ brgr
 bbrgwb"#;
 
 #[test]
-fn test_part1() {
-    let day = Day19 {};
-    assert_eq!(day.solve_part1(SAMPLE), "6");
-}
-
-#[test]
-fn test_part2() {
+fn test_separate_towel_sets() {
     let day = Day19 {};
-    assert_eq!(day.solve_part2(SAMPLE), "16");
+    assert_eq!(day.solve_part2(SAMPLE).unwrap(), "16");
+    // The same patterns must be recounted for a different set of towels
+    assert_eq!(day.solve_part2("r, b\n\nbrwrr\nrb").unwrap(), "1");
 }
@@ -0,0 +1,30 @@
+use crate::utils::random::Rng;
+
+// `size` reports, about half of them safe and some off by a single bad level
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let length = rng.range(5..=8);
+            let direction = if rng.chance(0.5) { 1 } else { -1 };
+            let mut level: i32 = rng.range(30..=60);
+            let mut levels = vec![level];
+
+            for _ in 1..length {
+                level += direction * rng.range(1..=3);
+                levels.push(level);
+            }
+
+            if rng.chance(0.5) {
+                let index = rng.below(length as u64) as usize;
+                levels[index] += rng.range(-4..=4);
+            }
+
+            levels
+                .iter()
+                .map(|level| level.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+                + "\n"
+        })
+        .collect()
+}
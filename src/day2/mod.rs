@@ -1,68 +1,100 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Part, Solution};
+use crate::utils::parsing::{for_each_line, non_empty_lines, parse_number};
+use std::io::BufRead;
+pub mod generate;
 #[cfg(test)]
 mod test;
 pub struct Day2 {}
 
-fn parse_input(input: &str) -> Vec<Vec<i32>> {
-    input
-        .trim()
-        .lines()
-        .skip_while(|&x| x.is_empty())
-        .map(|line| {
-            let split = line.trim().split_whitespace();
-            let numbers = split
-                .map(|number| number.parse::<i32>().expect("Could not parse number"))
-                .collect::<Vec<i32>>();
-            numbers
-        })
+fn parse_report(input: &str, line: &str) -> Result<Vec<i32>, SolveError> {
+    line.split_whitespace()
+        .map(|number| parse_number::<i32>(input, number))
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, SolveError> {
+    non_empty_lines(input)
+        .map(|line| parse_report(input, line))
         .collect()
 }
 
-fn is_safe(numbers: &Vec<i32>) -> bool {
+pub fn is_safe(numbers: &[i32]) -> bool {
     numbers.windows(2).all(|w| {
         let diff = w[1] - w[0];
-        1 <= diff && diff <= 3
+        (1..=3).contains(&diff)
     })
 }
 
-fn is_safe_with_removal(numbers: &Vec<i32>) -> bool {
+pub fn is_safe_with_removal(numbers: &[i32]) -> bool {
     if is_safe(numbers) {
         return true;
     }
 
     (0..numbers.len())
         .map(|i| {
-            let mut vec = numbers.clone();
+            let mut vec = numbers.to_vec();
             vec.remove(i);
             vec
         })
         .any(|vec| is_safe(&vec))
 }
 
-fn is_safe_bidirectional(numbers: &Vec<i32>, allow_removal: bool) -> bool {
-    let reversed = &numbers.iter().rev().cloned().collect();
+fn is_safe_bidirectional(numbers: &[i32], allow_removal: bool) -> bool {
+    let reversed: &Vec<i32> = &numbers.iter().rev().cloned().collect();
     if allow_removal {
-        is_safe_with_removal(&numbers) || is_safe_with_removal(reversed)
+        is_safe_with_removal(numbers) || is_safe_with_removal(reversed)
     } else {
-        is_safe(&numbers) || is_safe(reversed)
+        is_safe(numbers) || is_safe(reversed)
     }
 }
 
-fn count_safe_reports(input: &str, allow_removal: bool) -> usize {
-    let reports = parse_input(input);
+fn count_safe_reports(input: &str, allow_removal: bool) -> Result<usize, SolveError> {
+    let reports = parse_input(input)?;
 
-    reports
+    Ok(reports
         .iter()
-        .filter(|r| is_safe_bidirectional(&r, allow_removal))
-        .count()
+        .filter(|r| is_safe_bidirectional(r, allow_removal))
+        .count())
+}
+
+// Only one report is kept in memory at a time
+fn count_safe_reports_streaming(
+    reader: &mut dyn BufRead,
+    allow_removal: bool,
+) -> Result<usize, SolveError> {
+    let mut count = 0;
+
+    for_each_line(reader, |line| {
+        let report = parse_report(line, line)?;
+        if is_safe_bidirectional(&report, allow_removal) {
+            count += 1;
+        }
+        Ok(())
+    })?;
+
+    Ok(count)
 }
 
 impl Solution for Day2 {
-    fn solve_part1(&self, input: &str) -> String {
-        count_safe_reports(input, false).to_string()
+    fn solve_part_streaming(
+        &self,
+        part: Part,
+        reader: &mut dyn BufRead,
+    ) -> Option<Result<Answer, SolveError>> {
+        let allow_removal = part == Part::Two;
+        Some(count_safe_reports_streaming(reader, allow_removal).map(Answer::from))
+    }
+
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(count_safe_reports(input, false)?.into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        count_safe_reports(input, true).to_string()
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(count_safe_reports(input, true)?.into())
     }
 }
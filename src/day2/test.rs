@@ -1,5 +1,5 @@
 use crate::day2::Day2;
-use crate::solution::Solution;
+use crate::solution::{Part, Solution};
 
 static SAMPLE: &str = r#"
     7 6 4 2 1
@@ -11,13 +11,10 @@ static SAMPLE: &str = r#"
     "#;
 
 #[test]
-fn test_part1() {
+fn test_streaming() {
     let day = Day2 {};
-    assert_eq!(day.solve_part1(SAMPLE), "2");
-}
-
-#[test]
-fn test_part2() {
-    let day = Day2 {};
-    assert_eq!(day.solve_part2(SAMPLE), "4");
+    for (part, expected) in [(Part::One, "2"), (Part::Two, "4")] {
+        let answer = day.solve_part_streaming(part, &mut SAMPLE.as_bytes());
+        assert_eq!(answer.unwrap().unwrap(), expected);
+    }
 }
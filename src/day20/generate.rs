@@ -0,0 +1,44 @@
+use crate::generate::{maze, odd_side, render_grid};
+use crate::utils::random::Rng;
+use std::collections::{HashMap, VecDeque};
+
+// A race track of about `size` by `size`. The track is the path from a random tile of a perfect
+// maze to the tile furthest from it, so there is a single track without branches.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = odd_side(size);
+    let grid = maze(rng, side);
+
+    let start = (
+        2 * rng.below((side / 2) as u64) as usize + 1,
+        2 * rng.below((side / 2) as u64) as usize + 1,
+    );
+    let mut previous = HashMap::from([(start, start)]);
+    let mut queue = VecDeque::from([start]);
+    let mut end = start;
+
+    while let Some((row, col)) = queue.pop_front() {
+        end = (row, col);
+        for next in [
+            (row - 1, col),
+            (row + 1, col),
+            (row, col - 1),
+            (row, col + 1),
+        ] {
+            if grid[next.0][next.1] == '.' && !previous.contains_key(&next) {
+                previous.insert(next, (row, col));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let mut track = vec![vec!['#'; side]; side];
+    let mut tile = end;
+    while tile != start {
+        track[tile.0][tile.1] = '.';
+        tile = previous[&tile];
+    }
+
+    track[start.0][start.1] = 'S';
+    track[end.0][end.1] = 'E';
+    render_grid(&track)
+}
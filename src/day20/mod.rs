@@ -1,43 +1,72 @@
-use crate::solution::Solution;
-use crate::utils::geometry::Direction;
-use crate::utils::nalgebra::{MatrixHelpers, MatrixParser, VectorHelpers};
-use nalgebra::{DMatrix, Vector2};
+use crate::error::SolveError;
+use crate::params::{parameters, Parameters};
+use crate::solution::{Answer, Solution};
+use crate::utils::geometry::manhattan_ring;
+use crate::utils::grid::{Grid, Tile};
+use crate::utils::render::{self, Canvas, Color, Colored, Image};
+use nalgebra::Vector2;
 use rayon::{iter::IntoParallelIterator, iter::ParallelIterator};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
-use strum::IntoEnumIterator;
 
+pub mod generate;
+#[cfg(test)]
+mod oracle;
 #[cfg(test)]
 mod test;
-pub struct Day20 {}
+
+parameters! {
+    pub struct Day20Params {
+        part1_cheat: usize = 2,
+        part2_cheat: usize = 20,
+        // Picoseconds a cheat has to save to be counted
+        min_saving: i32 = 100,
+    }
+}
+
+#[derive(Default)]
+pub struct Day20 {
+    pub params: Day20Params,
+}
 
 #[derive(Debug, Clone, PartialEq)]
-enum RaceTile {
+pub enum RaceTile {
     Start,
     End,
     Floor,
     Wall,
 }
 
-impl fmt::Display for RaceTile {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+impl Tile for RaceTile {
+    fn to_char(&self) -> char {
         match self {
-            RaceTile::Start => fmt.write_str("S")?,
-            RaceTile::End => fmt.write_str("E")?,
-            RaceTile::Floor => fmt.write_str(".")?,
-            RaceTile::Wall => fmt.write_str("#")?,
+            RaceTile::Start => 'S',
+            RaceTile::End => 'E',
+            RaceTile::Floor => '.',
+            RaceTile::Wall => '#',
         }
+    }
+}
 
-        Ok(())
+impl Colored for RaceTile {
+    fn color(&self) -> Color {
+        match self {
+            RaceTile::Start => render::GREEN,
+            RaceTile::End => render::RED,
+            RaceTile::Floor => render::WHITE,
+            RaceTile::Wall => render::GRAY,
+        }
     }
 }
 
 #[derive(Debug, Clone)]
-struct RaceTrack {
-    field: DMatrix<RaceTile>,
+pub struct RaceTrack {
+    field: Grid<RaceTile>,
+    start: Position,
+    end: Position,
 }
 
-type Position = Vector2<i32>;
+pub type Position = Vector2<i32>;
 
 struct RaceTrackPath<'a> {
     track: &'a RaceTrack,
@@ -45,80 +74,77 @@ struct RaceTrackPath<'a> {
 }
 
 impl RaceTrack {
-    fn within_bounds(&self, p: &Position) -> bool {
-        p.x >= 0 && p.x < self.field.ncols() as i32 && p.y >= 0 && p.y < self.field.nrows() as i32
-    }
-
     fn neighbors(&self, pos: &Position, tile_types: Vec<RaceTile>) -> Vec<Position> {
-        Direction::iter()
-            .map(|d| pos + d.to_vector())
-            .filter(|p| self.within_bounds(p))
-            .filter(|p| tile_types.contains(&self.field[p.to_matrix_index()]))
+        self.field
+            .neighbors4(pos)
+            .filter(|p| tile_types.contains(&self.field[*p]))
             .collect()
     }
 
-    fn new(input: &str) -> Self {
-        let field = input.to_string().to_matrix(|c| match c {
-            'S' => RaceTile::Start,
-            'E' => RaceTile::End,
-            '.' => RaceTile::Floor,
-            '#' => RaceTile::Wall,
-            _ => panic!("Could not match race tile '{c}'"),
+    pub fn new(input: &str) -> Result<Self, SolveError> {
+        let (field, markers) = Grid::parse_with_markers(input, &['S', 'E'], |c| match c {
+            'S' => Some(RaceTile::Start),
+            'E' => Some(RaceTile::End),
+            '.' => Some(RaceTile::Floor),
+            '#' => Some(RaceTile::Wall),
+            _ => None,
+        })?;
+
+        let [start, end] = [('S', "start"), ('E', "end")].map(|(marker, name)| {
+            markers.first(marker).ok_or_else(|| {
+                SolveError::invalid_input(
+                    input,
+                    input.trim(),
+                    format!("the race track has no {} tile", name),
+                )
+            })
         });
-        Self { field }
-    }
-
-    fn start_position(&self) -> Position {
-        self.field.find_index(RaceTile::Start).unwrap()
-    }
 
-    fn end_position(&self) -> Position {
-        self.field.find_index(RaceTile::End).unwrap()
+        Ok(Self {
+            field,
+            start: start?,
+            end: end?,
+        })
     }
 
-    fn step(&self, position: &Position, history: &HashSet<Position>) -> Position {
+    fn step(&self, position: &Position, history: &HashSet<Position>) -> Option<Position> {
         self.neighbors(position, vec![RaceTile::Floor, RaceTile::End])
-            .iter()
-            .filter(|p| !history.contains(p))
-            .next()
-            .unwrap()
-            .clone()
+            .into_iter()
+            .find(|p| !history.contains(p))
     }
 
-    fn find_path(&self) -> RaceTrackPath {
-        let mut current = self.start_position();
+    fn find_path(&self) -> Result<RaceTrackPath<'_>, SolveError> {
+        let mut current = self.start;
+        let end = self.end;
         let mut path = vec![];
         let mut visited = HashSet::new();
 
-        while current != self.end_position() {
+        while current != end {
             path.push(current);
             visited.insert(current);
 
-            current = self.step(&current, &visited);
+            current = self.step(&current, &visited).ok_or_else(|| {
+                SolveError::no_solution(format!(
+                    "the race track ends at {},{} before reaching the end",
+                    current.x, current.y
+                ))
+            })?;
         }
 
         // Add end to path
         path.push(current);
 
-        RaceTrackPath {
-            track: self,
-            path,
-        }
+        Ok(RaceTrackPath { track: self, path })
     }
 
-    fn manhattan_neighbors(position: &Position, distance: usize) -> Vec<Position> {
-        let mut neighbors = Vec::new();
-        let int_distance = distance as i32;
-
-        for x in -int_distance..=int_distance {
-            let y = int_distance - x.abs();
-            neighbors.push(position + Vector2::new(x, y));
-            if y != 0 {
-                neighbors.push(position + Vector2::new(x, -y));
-            }
-        }
+    pub fn to_image(&self) -> Result<Image, SolveError> {
+        let path = self.find_path()?.path;
 
-        neighbors
+        // Start and end are drawn last so the path does not hide them
+        Ok(Image::from_grid(&self.field, RaceTile::color)
+            .with_overlay(path, render::BLUE)
+            .with_overlay([self.start], RaceTile::Start.color())
+            .with_overlay([self.end], RaceTile::End.color()))
     }
 
     fn find_jumps<'a>(
@@ -126,19 +152,14 @@ impl RaceTrack {
         position: &'a Position,
         jump_size: usize,
     ) -> Vec<(&'a Position, Position)> {
-        let jumps = Self::manhattan_neighbors(position, jump_size);
-        let landings = jumps
-            .iter()
-            .filter(|p| self.within_bounds(p))
-            .filter(|p| self.field[p.to_matrix_index()] != RaceTile::Wall)
-            .map(|&p| (position, p))
-            .collect();
-
-        landings
+        manhattan_ring(*position, jump_size as u32)
+            .filter(|p| self.field.get(p).is_some_and(|t| *t != RaceTile::Wall))
+            .map(|p| (position, p))
+            .collect()
     }
 
-    fn find_cheats(&self, max_jump_size: usize) -> Vec<i32> {
-        let path = self.find_path().path;
+    pub fn find_cheats(&self, max_jump_size: usize) -> Result<Vec<i32>, SolveError> {
+        let path = self.find_path()?.path;
 
         let index_map = path
             .iter()
@@ -154,45 +175,61 @@ impl RaceTrack {
                     .map(|(start, end)| (start, end, jump_size))
                     .collect::<Vec<(&Position, Position, usize)>>()
             })
-            .map(|(start, end, jump_size)| (index_map[&start], index_map[&end], jump_size))
+            .map(|(start, end, jump_size)| (index_map[start], index_map[&end], jump_size))
             .map(|(start_pos, end_pos, jump_size)| end_pos - (start_pos + jump_size as i32))
             .filter(|&s| s > 0)
             .collect();
 
-        cheats
+        Ok(cheats)
     }
 }
 
-impl<'a> fmt::Display for RaceTrackPath<'a> {
+impl fmt::Display for RaceTrackPath<'_> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        for row in 0..self.track.field.nrows() {
-            for col in 0..self.track.field.ncols() {
-                let v = Position::new(col as i32, row as i32);
-                if self.path.contains(&v) {
-                    write!(fmt, "O")?;
-                } else {
-                    write!(fmt, "{}", self.track.field[v.to_matrix_index()])?;
-                }
-            }
-            write!(fmt, "\n")?;
-        }
-
-        Ok(())
+        let canvas = Canvas::from_colored_grid(&self.track.field).with_highlight(
+            self.path.iter().copied(),
+            'O',
+            render::BLUE,
+            "race path",
+        );
+
+        write!(fmt, "{}", canvas)
     }
 }
 
 impl Solution for Day20 {
-    fn solve_part1(&self, input: &str) -> String {
-        let track = RaceTrack::new(input);
-        let cheats = track.find_cheats(2);
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        RaceTrack::new(input).map(|_| ())
+    }
+
+    // The race track with the path from start to end
+    fn render(&self, input: &str) -> Option<Result<Image, SolveError>> {
+        Some(RaceTrack::new(input).and_then(|track| track.to_image()))
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let track = RaceTrack::new(input)?;
+        let cheats = track.find_cheats(self.params.part1_cheat)?;
 
-        cheats.iter().filter(|&&c| c >= 100).count().to_string()
+        Ok(cheats
+            .iter()
+            .filter(|&&c| c >= self.params.min_saving)
+            .count()
+            .into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let track = RaceTrack::new(input);
-        let cheats = track.find_cheats(20);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let track = RaceTrack::new(input)?;
+        let cheats = track.find_cheats(self.params.part2_cheat)?;
 
-        cheats.iter().filter(|&&c| c >= 100).count().to_string()
+        Ok(cheats
+            .iter()
+            .filter(|&&c| c >= self.params.min_saving)
+            .count()
+            .into())
     }
 }
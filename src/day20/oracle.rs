@@ -0,0 +1,43 @@
+use std::collections::{HashMap, VecDeque};
+
+// Compares every pair of track tiles within `max_jump` steps of each other, using the distances of a
+// breadth-first search from the start
+pub fn cheat_savings(input: &str, max_jump: usize) -> Vec<i32> {
+    let tiles: HashMap<(i32, i32), char> = input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(move |(x, tile)| ((x as i32, y as i32), tile))
+        })
+        .filter(|&(_, tile)| tile != '#')
+        .collect();
+
+    let start = tiles.iter().find(|(_, &tile)| tile == 'S').map(|(&p, _)| p);
+    let mut distances = HashMap::from([(start.unwrap(), 0)]);
+    let mut queue: VecDeque<(i32, i32)> = distances.keys().copied().collect();
+
+    while let Some((x, y)) = queue.pop_front() {
+        for next in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+            if tiles.contains_key(&next) && !distances.contains_key(&next) {
+                distances.insert(next, distances[&(x, y)] + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let mut savings = vec![];
+    for (from, from_distance) in &distances {
+        for (to, to_distance) in &distances {
+            let jump = (from.0 - to.0).abs() + (from.1 - to.1).abs();
+            let saving = to_distance - from_distance - jump;
+            if jump as usize <= max_jump && saving > 0 {
+                savings.push(saving);
+            }
+        }
+    }
+
+    savings.sort();
+    savings
+}
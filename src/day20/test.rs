@@ -1,32 +1,88 @@
-use crate::day20::Day20;
+use crate::day20::{oracle, Day20, RaceTrack};
+use crate::property::{assert_property, expect_eq};
 use crate::solution::Solution;
-
-static SAMPLE: &str = r#"
-###############
-#...#...#.....#
-#.#.#.#.#.###.#
-#S#...#.#.#...#
-#######.#.#.###
-#######.#.#...#
-#######.#.###.#
-###..E#...#...#
-###.#######.###
-#...###...#...#
-#.#####.#.###.#
-#.#...#.#.#...#
-#.#.#.#.#.#.###
-#...#...#...###
-###############
-"#;
+use crate::utils::random::Rng;
 
 #[test]
-fn test_part1() {
-    let day = Day20 {};
-    assert_eq!(day.solve_part1(SAMPLE), "");
+fn test_part2() {
+    let day = Day20::default();
+    assert!(day.solve_part2("").is_err());
+}
+
+static MOVES: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+// Follows the moves from the start, the track may only touch itself at the previous tile so there
+// is a single way through
+fn track(moves: &[u8]) -> Option<String> {
+    let mut path = vec![(0, 0)];
+
+    for &index in moves {
+        let (dx, dy) = *MOVES.get(index as usize)?;
+        let &(x, y) = path.last().unwrap();
+        let next = (x + dx, y + dy);
+
+        let touches = path[..path.len() - 1]
+            .iter()
+            .any(|&(px, py)| (px - next.0).abs() + (py - next.1).abs() <= 1);
+        if touches {
+            return None;
+        }
+        path.push(next);
+    }
+
+    if path.len() < 2 {
+        return None;
+    }
+
+    let min_x = path.iter().map(|p| p.0).min().unwrap() - 1;
+    let min_y = path.iter().map(|p| p.1).min().unwrap() - 1;
+    let width = (path.iter().map(|p| p.0).max().unwrap() - min_x + 2) as usize;
+    let height = (path.iter().map(|p| p.1).max().unwrap() - min_y + 2) as usize;
+
+    let mut grid = vec![vec!['#'; width]; height];
+    for (i, &(x, y)) in path.iter().enumerate() {
+        grid[(y - min_y) as usize][(x - min_x) as usize] = match i {
+            0 => 'S',
+            _ if i == path.len() - 1 => 'E',
+            _ => '.',
+        };
+    }
+
+    Some(
+        grid.iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect(),
+    )
+}
+
+fn walk(rng: &mut Rng) -> (Vec<u8>, usize) {
+    let mut moves = vec![];
+
+    for _ in 0..rng.range(1..=40) {
+        let options: Vec<u8> = (0..4)
+            .filter(|&index| track(&[moves.as_slice(), &[index]].concat()).is_some())
+            .collect();
+        if options.is_empty() {
+            break;
+        }
+        moves.push(*rng.choose(&options));
+    }
+
+    (moves, rng.range(1..=6))
 }
 
 #[test]
-fn test_part2() {
-    let day = Day20 {};
-    assert_eq!(day.solve_part2(""), "");
+fn test_find_cheats_matches_oracle() {
+    assert_property(walk, |(moves, max_jump)| {
+        let Some(input) = track(moves) else {
+            return Ok(());
+        };
+
+        let mut cheats = RaceTrack::new(&input)
+            .and_then(|track| track.find_cheats(*max_jump))
+            .map_err(|e| e.to_string())?;
+        cheats.sort();
+
+        expect_eq(cheats, oracle::cheat_savings(&input, *max_jump))
+    });
 }
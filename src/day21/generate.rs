@@ -0,0 +1,8 @@
+use crate::utils::random::Rng;
+
+// `size` door codes of three digits
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{:03}A\n", rng.range(0..=999)))
+        .collect()
+}
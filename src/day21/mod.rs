@@ -1,16 +1,34 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::params::{parameters, Parameters};
+use crate::solution::{Answer, Solution};
 use crate::utils::geometry::Direction;
-use crate::utils::nalgebra::MatrixHelpers;
+use crate::utils::memo::Memo;
+use crate::utils::nalgebra::{MatrixHelpers, VectorHelpers};
+use crate::utils::parsing::{non_empty_lines, parse_number};
+use crate::utils::search;
 use itertools::Itertools;
-use memoize::memoize;
-use nalgebra::{Matrix2x3, Matrix4x3};
+use log::debug;
+use nalgebra::{Matrix2x3, Matrix4x3, Vector2};
 use std::iter;
 use strum::IntoEnumIterator;
 
 static NA: char = '~';
+pub mod generate;
 #[cfg(test)]
 mod test;
-pub struct Day21 {}
+
+parameters! {
+    pub struct Day21Params {
+        // Directional keypads operated by robots
+        part1_robots: usize = 2,
+        part2_robots: usize = 25,
+    }
+}
+
+#[derive(Default)]
+pub struct Day21 {
+    pub params: Day21Params,
+}
 
 static DIRECTIONAL_KEYPAD_LAYOUT: Matrix2x3<char> = Matrix2x3::new(NA, '^', 'A', '<', 'v', '>');
 static NUMPAD_KEYPAD_LAYOUT: Matrix4x3<char> =
@@ -25,45 +43,28 @@ fn direction_to_key(direction: Direction) -> char {
     }
 }
 
-#[memoize]
-fn numpad_paths(start: char, end: char)  -> Vec<String> {
-    keypad_paths(
-        start,
-        end,
-        NUMPAD_KEYPAD_LAYOUT
-    )
+fn key_position<Z: MatrixHelpers<char>>(key: char, pad: &Z) -> Result<Vector2<i32>, SolveError> {
+    pad.find_index(key)
+        .ok_or_else(|| SolveError::no_solution(format!("the keypad has no key '{}'", key)))
 }
 
-#[memoize]
-fn dirpad_paths(start: char, end: char)  -> Vec<String> {
-    keypad_paths(
-        start,
-        end,
-        DIRECTIONAL_KEYPAD_LAYOUT
-    )
-}
+fn keypad_paths<Z: MatrixHelpers<char> + Clone>(
+    start: char,
+    end: char,
+    pad: Z,
+) -> Result<Vec<String>, SolveError> {
+    let dest = key_position(end, &pad)?;
 
-fn keypad_paths<Z : MatrixHelpers<char> + Clone>(start: char, end: char, pad: Z) -> Vec<String> {
-    let dest = pad.find_index(end).unwrap();
-
-    let sol = pathfinding::prelude::astar_bag_collect(
-        &(start, None),
+    let search = search::a_star(
+        (key_position(start, &pad)?, None),
         |(current, dir)| {
             let adj: Vec<_> = Direction::iter()
-                .map(|d| {
-                    (
-                        pad.find_index(*current).unwrap() + d.to_vector(),
-                        d,
-                    )
-                })
-                .filter(|(l, _)| pad.valid_index(l))
-                .map(|(l, d)| (pad.at(&l), d))
-                .filter(|(c, _)| **c != NA)
+                .map(|d| (current + d.to_vector(), d))
+                .filter(|(l, _)| pad.valid_index(l) && *pad.at(l) != NA)
                 .collect();
 
-            return adj
-                .into_iter()
-                .map(|(c, d)| {
+            adj.into_iter()
+                .map(|(l, d)| {
                     let cost;
                     if let Some(current_dir) = dir {
                         cost = if *current_dir == d { 1 } else { 2 }
@@ -71,88 +72,164 @@ fn keypad_paths<Z : MatrixHelpers<char> + Clone>(start: char, end: char, pad: Z)
                         cost = 1
                     }
 
-                    ((*c, Some(d)), cost)
+                    ((l, Some(d)), cost)
                 })
-                .collect::<Vec<((char, Option<Direction>), u32)>>();
-        },
-        |(current, _)| {
-            (pad.find_index(*current).unwrap() - dest)
-                .abs()
-                .sum() as u32
+                .collect::<Vec<((Vector2<i32>, Option<Direction>), u32)>>()
         },
-        |&(current, _)| current == end,
-    )
-        .unwrap();
+        |(current, _)| current.manhattan_distance(&dest) as u32,
+        |&(current, _)| current == dest,
+    );
 
-    let (paths, _) = sol;
-    paths
+    Ok(search
+        .all_paths()
         .into_iter()
         .map(|path| {
             path.iter()
                 .filter_map(|(_, d)| d.clone())
-                .map(|d| direction_to_key(d))
+                .map(direction_to_key)
                 .collect()
         })
         .map(|keys: String| keys + "A")
-        .collect()
+        .collect())
 }
 
-#[memoize]
-fn npad(input: String, robots: usize) -> usize {
-    let mut sum = 0;
-    for (a, b) in iter::once('A').chain(input.chars()).tuple_windows() {
-        let sequences = numpad_paths(a, b);
-
-        sum += sequences
-            .into_iter()
-            .map(|s| dpad(s, robots))
-            .min()
-            .unwrap();
-    }
-    sum
+// The length of the shortest of the sequences that move from key `a` to key `b`
+fn shortest(lengths: Vec<usize>, a: char, b: char) -> Result<usize, SolveError> {
+    lengths.into_iter().min().ok_or_else(|| {
+        SolveError::no_solution(format!("key '{}' cannot be reached from key '{}'", b, a))
+    })
+}
+
+// With enough robots the length of a sequence no longer fits in a `usize`
+fn too_long() -> SolveError {
+    SolveError::no_solution("the sequence is too long to count")
+}
+
+#[derive(Default)]
+pub struct KeypadSolver {
+    numpad_paths: Memo<(char, char), Vec<String>>,
+    dirpad_paths: Memo<(char, char), Vec<String>>,
+    npad: Memo<(String, usize), usize>,
+    dpad: Memo<(String, usize), usize>,
 }
 
-#[memoize]
-fn dpad(input: String, robots: usize) -> usize {
-    let mut acc = 0;
+impl KeypadSolver {
+    fn numpad_paths(&mut self, start: char, end: char) -> Result<Vec<String>, SolveError> {
+        match self.numpad_paths.get(&(start, end)) {
+            Some(paths) => Ok(paths),
+            None => Ok(self.numpad_paths.insert(
+                (start, end),
+                keypad_paths(start, end, NUMPAD_KEYPAD_LAYOUT)?,
+            )),
+        }
+    }
+
+    fn dirpad_paths(&mut self, start: char, end: char) -> Result<Vec<String>, SolveError> {
+        match self.dirpad_paths.get(&(start, end)) {
+            Some(paths) => Ok(paths),
+            None => Ok(self.dirpad_paths.insert(
+                (start, end),
+                keypad_paths(start, end, DIRECTIONAL_KEYPAD_LAYOUT)?,
+            )),
+        }
+    }
+
+    pub fn npad(&mut self, input: String, robots: usize) -> Result<usize, SolveError> {
+        if let Some(length) = self.npad.get(&(input.clone(), robots)) {
+            return Ok(length);
+        }
 
-    for (a, b) in iter::once('A').chain(input.chars()).tuple_windows() {
-        let sequences = dirpad_paths(a, b);
-        let depth = robots - 1;
-        if depth > 0 {
-            acc += sequences
+        let mut sum: usize = 0;
+        for (a, b) in iter::once('A').chain(input.chars()).tuple_windows() {
+            let lengths = self
+                .numpad_paths(a, b)?
                 .into_iter()
-                .map(|s| dpad(s, depth))
-                .min()
-                .unwrap();
-        } else {
-            acc += sequences
+                .map(|s| self.dpad(s, robots))
+                .collect::<Result<Vec<usize>, SolveError>>()?;
+
+            sum = sum
+                .checked_add(shortest(lengths, a, b)?)
+                .ok_or_else(too_long)?;
+        }
+
+        Ok(self.npad.insert((input, robots), sum))
+    }
+
+    pub fn dpad(&mut self, input: String, robots: usize) -> Result<usize, SolveError> {
+        // Without robots left, the sequence is typed directly
+        if robots == 0 {
+            return Ok(input.len());
+        }
+        if let Some(length) = self.dpad.get(&(input.clone(), robots)) {
+            return Ok(length);
+        }
+
+        let mut acc: usize = 0;
+
+        for (a, b) in iter::once('A').chain(input.chars()).tuple_windows() {
+            let lengths = self
+                .dirpad_paths(a, b)?
                 .into_iter()
-                .map(|s| s.len())
-                .min()
-                .unwrap();
+                .map(|s| self.dpad(s, robots - 1))
+                .collect::<Result<Vec<usize>, SolveError>>()?;
+
+            acc = acc
+                .checked_add(shortest(lengths, a, b)?)
+                .ok_or_else(too_long)?;
         }
+
+        Ok(self.dpad.insert((input, robots), acc))
     }
+}
 
-    acc
+pub fn parse_input(input: &str) -> Result<Vec<(&str, usize)>, SolveError> {
+    non_empty_lines(input)
+        .map(|code| {
+            let digits = code
+                .strip_suffix("A")
+                .filter(|d| !d.is_empty() && d.chars().all(|c| c.is_ascii_digit()))
+                .ok_or_else(|| {
+                    SolveError::invalid_input(
+                        input,
+                        code,
+                        "expected a code of digits ending in 'A'",
+                    )
+                })?;
+            Ok((code, parse_number::<usize>(input, digits)?))
+        })
+        .collect()
 }
 
-fn type_code(input: &str, dirpad_count: usize) -> usize {
-    let mut sum = 0;
-    for code in input.trim().lines().map(|l| l.trim()) {
-        let code_num = code.strip_suffix("A").unwrap().parse::<usize>().unwrap();
-        let length = npad(code.into(), dirpad_count);
-        sum += code_num * length;
+pub fn type_code(input: &str, dirpad_count: usize) -> Result<usize, SolveError> {
+    let mut solver = KeypadSolver::default();
+    let mut sum: usize = 0;
+    for (code, code_num) in parse_input(input)? {
+        let length = solver.npad(code.into(), dirpad_count)?;
+        sum = code_num
+            .checked_mul(length)
+            .and_then(|complexity| sum.checked_add(complexity))
+            .ok_or_else(too_long)?;
     }
-    sum
+
+    debug!("numpad cache: {}", solver.npad.stats());
+    debug!("dirpad cache: {}", solver.dpad.stats());
+    Ok(sum)
 }
 
 impl Solution for Day21 {
-    fn solve_part1(&self, input: &str) -> String {
-        type_code(input, 2).to_string()
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(type_code(input, self.params.part1_robots)?.into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        type_code(input, 25).to_string()
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(type_code(input, self.params.part2_robots)?.into())
     }
 }
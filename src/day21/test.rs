@@ -1,17 +1,13 @@
-use crate::day21::{Day21};
+use crate::day21::{Day21, Day21Params};
 use crate::solution::Solution;
 
 #[test]
-fn test_part1() {
-    let day = Day21 {};
-    assert_eq!(
-        day.solve_part1(r#"
-        029A
-        980A
-        179A
-        456A
-        379A
-        "#),
-        "126384"
-    );
-}
\ No newline at end of file
+fn test_too_many_robots() {
+    let day = Day21 {
+        params: Day21Params {
+            part2_robots: 100,
+            ..Default::default()
+        },
+    };
+    assert!(day.solve_part2("029A").is_err());
+}
@@ -0,0 +1,8 @@
+use crate::utils::random::Rng;
+
+// The initial secret numbers of `size` buyers
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n", rng.range(1..=16777215)))
+        .collect()
+}
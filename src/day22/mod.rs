@@ -1,26 +1,45 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::params::{at_least, parameters, Parameters};
+use crate::solution::{Answer, Part, Solution};
+use crate::utils::parsing::{for_each_line, non_empty_lines, parse_number};
+use log::{debug, info};
 use rayon::iter::IndexedParallelIterator;
-use rayon::iter::ParallelIterator;
 use rayon::iter::IntoParallelIterator;
+use rayon::iter::ParallelIterator;
 use std::cell::OnceCell;
 use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
 
+pub mod generate;
 #[cfg(test)]
 mod test;
-pub struct Day22 {}
+
+parameters! {
+    pub struct Day22Params {
+        iterations: u32 = 2000 => at_least(4),
+    }
+}
+
+#[derive(Default)]
+pub struct Day22 {
+    pub params: Day22Params,
+}
+
+// Number of consecutive price changes the monkey watches for
+const STRATEGY_LENGTH: usize = 4;
 
 #[derive(Clone)]
-struct Buyer {
+pub struct Buyer {
     secret_number: u64,
     count: u32,
     evolved_values: OnceCell<Vec<u64>>,
     evolved_ones: OnceCell<Vec<u32>>,
     price_changes: OnceCell<Vec<i32>>,
-    strategies: OnceCell<HashSet<Vec<i32>>>
+    strategies: OnceCell<HashSet<Vec<i32>>>,
 }
 
 impl Buyer {
-    fn new(secret_number: u64, count: u32) -> Buyer {
+    pub fn new(secret_number: u64, count: u32) -> Buyer {
         Self {
             secret_number,
             count,
@@ -31,15 +50,12 @@ impl Buyer {
         }
     }
 
-    fn evolve(&self) -> &Vec<u64> {
+    pub fn evolve(&self) -> &Vec<u64> {
         self.evolved_values.get_or_init(|| {
             let mut current = self.secret_number;
             let mut results = Vec::from([current]);
             for _ in 0..self.count {
-                current = Self::prune(Self::mix(current * 64, current));
-                current = Self::prune(Self::mix(current / 32, current));
-                current = Self::prune(Self::mix(current * 2048, current));
-
+                current = Self::next_secret(current);
                 results.push(current);
             }
 
@@ -54,30 +70,35 @@ impl Buyer {
         })
     }
 
-    fn price_changes(&self) -> &Vec<i32> {
+    pub fn price_changes(&self) -> &Vec<i32> {
         self.price_changes.get_or_init(|| {
-            self.evolve_ones().windows(2).map(|w| w[1] as i32 - w[0] as i32).collect()
+            self.evolve_ones()
+                .windows(2)
+                .map(|w| w[1] as i32 - w[0] as i32)
+                .collect()
         })
     }
 
-    fn find_strategies(&self) -> &HashSet<Vec<i32>> {
+    pub fn find_strategies(&self) -> &HashSet<Vec<i32>> {
         self.strategies.get_or_init(|| {
             self.price_changes()
-                .windows(4)
+                .windows(STRATEGY_LENGTH)
                 .map(|w| w.to_vec())
                 .collect::<HashSet<Vec<i32>>>()
         })
     }
 
-    fn apply_strategies(&self, strategies: &HashSet<&Vec<i32>>) -> HashMap<Vec<i32>, u32> {
+    pub fn apply_strategies(&self, strategies: &HashSet<&Vec<i32>>) -> HashMap<Vec<i32>, u32> {
         let changes = self.price_changes();
-        let strategy_length = strategies.iter().next().unwrap().len();
-        let mut strategy_profit = strategies.iter().map(|&s| (s.clone(), 0)).collect::<HashMap<Vec<i32>, u32>>();
+        let mut strategy_profit = strategies
+            .iter()
+            .map(|&s| (s.clone(), 0))
+            .collect::<HashMap<Vec<i32>, u32>>();
 
-        for (index, window) in changes.windows(strategy_length).enumerate() {
-            if let Some(w) = strategy_profit.get_mut(&window.to_vec()) {
+        for (index, window) in changes.windows(STRATEGY_LENGTH).enumerate() {
+            if let Some(w) = strategy_profit.get_mut(window) {
                 if *w == 0 {
-                    *w = self.evolve_ones().get(index + strategy_length).unwrap().clone();
+                    *w = self.evolve_ones()[index + STRATEGY_LENGTH];
                 }
             }
         }
@@ -85,6 +106,12 @@ impl Buyer {
         strategy_profit
     }
 
+    pub fn next_secret(secret: u64) -> u64 {
+        let secret = Self::prune(Self::mix(secret * 64, secret));
+        let secret = Self::prune(Self::mix(secret / 32, secret));
+        Self::prune(Self::mix(secret * 2048, secret))
+    }
+
     fn prune(secret: u64) -> u64 {
         secret % 16777216
     }
@@ -94,14 +121,13 @@ impl Buyer {
     }
 }
 
-fn parse_input(input: &str) -> Vec<Buyer> {
-    input.trim().lines()
-        .map(|l| l.trim())
-        .map(|l| Buyer::new(l.parse().unwrap(), 2000))
+pub fn parse_input(input: &str, iterations: u32) -> Result<Vec<Buyer>, SolveError> {
+    non_empty_lines(input)
+        .map(|l| Ok(Buyer::new(parse_number(input, l)?, iterations)))
         .collect()
 }
 
-fn buy_strategy(buyers: Vec<Buyer>) -> u32 {
+pub fn buy_strategy(buyers: Vec<Buyer>) -> u32 {
     let strategies = buyers
         .iter()
         .flat_map(|b| b.find_strategies())
@@ -113,7 +139,7 @@ fn buy_strategy(buyers: Vec<Buyer>) -> u32 {
         .clone()
         .into_par_iter()
         .enumerate()
-        .inspect(|(i, _)| println!("processing buyer {}/{}", i, buyers_len))
+        .inspect(|(i, _)| debug!("processing buyer {}/{}", i, buyers_len))
         .map(|(_, b)| b.apply_strategies(&strategies))
         .collect();
 
@@ -126,7 +152,10 @@ fn buy_strategy(buyers: Vec<Buyer>) -> u32 {
     }
 
     if let Some((strategy, profit)) = result.iter().max_by_key(|&(_, v)| v) {
-        println!("Strategy {:?} is best and results in '{}'", strategy, profit);
+        info!(
+            "Strategy {:?} is best and results in '{}'",
+            strategy, profit
+        );
 
         return *profit;
     }
@@ -134,16 +163,75 @@ fn buy_strategy(buyers: Vec<Buyer>) -> u32 {
     0
 }
 
+// Every sequence of four price changes, each change in -9..=9
+const SEQUENCES: usize = 19 * 19 * 19 * 19;
+
+// Neither part keeps the evolved secrets. Part 2 adds the price at the first occurrence of each
+// sequence of changes to a table of all sequences, instead of collecting them per buyer.
+fn solve_streaming(
+    part: Part,
+    reader: &mut dyn BufRead,
+    iterations: u32,
+) -> Result<Answer, SolveError> {
+    let mut total = 0;
+    let mut profits = vec![0u32; SEQUENCES];
+    let mut last_buyer = vec![usize::MAX; SEQUENCES];
+    let mut buyer = 0;
+
+    for_each_line(reader, |line| {
+        let mut secret: u64 = parse_number(line, line)?;
+        let mut sequence = 0;
+
+        for i in 0..iterations {
+            let next = Buyer::next_secret(secret);
+            let change = (next % 10 + 9 - secret % 10) as usize;
+            sequence = (sequence * 19 + change) % SEQUENCES;
+            secret = next;
+
+            if part == Part::Two && i >= 3 && last_buyer[sequence] != buyer {
+                last_buyer[sequence] = buyer;
+                profits[sequence] += (secret % 10) as u32;
+            }
+        }
+
+        total += secret;
+        buyer += 1;
+        Ok(())
+    })?;
+
+    Ok(match part {
+        Part::One => total.into(),
+        Part::Two => profits.into_iter().max().unwrap_or(0).into(),
+    })
+}
+
 impl Solution for Day22 {
-    fn solve_part1(&self, input: &str) -> String {
-        let buyers = parse_input(input);
-        buyers.iter()
+    fn solve_part_streaming(
+        &self,
+        part: Part,
+        reader: &mut dyn BufRead,
+    ) -> Option<Result<Answer, SolveError>> {
+        Some(solve_streaming(part, reader, self.params.iterations))
+    }
+
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        self.params.values()
+    }
+
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input, self.params.iterations).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let buyers = parse_input(input, self.params.iterations)?;
+        Ok(buyers
+            .iter()
             .map(|buyer| *buyer.evolve().last().unwrap())
             .sum::<u64>()
-            .to_string()
+            .into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        buy_strategy(parse_input(input)).to_string()
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(buy_strategy(parse_input(input, self.params.iterations)?).into())
     }
 }
@@ -1,27 +1,5 @@
-use crate::day22::{Buyer, Day22};
-use crate::solution::Solution;
-
-#[test]
-fn test_part1() {
-    let day = Day22 {};
-    assert_eq!(day.solve_part1(r#"
-    1
-    10
-    100
-    2024
-    "#), "37327623");
-}
-
-#[test]
-fn test_part2() {
-    let day = Day22 {};
-    assert_eq!(day.solve_part2(r#"
-    1
-    2
-    3
-    2024
-    "#), "23");
-}
+use crate::day22::{Buyer, Day22, Day22Params};
+use crate::solution::{Part, Solution};
 
 #[test]
 fn test_ones() {
@@ -34,3 +12,37 @@ fn test_changes() {
     let buyer = Buyer::new(123, 5);
     assert_eq!(*buyer.price_changes(), vec![-3, 6, -1, -1, 0]);
 }
+
+#[test]
+fn test_streaming() {
+    let day = Day22::default();
+    let input = "1\n10\n100\n2024\n";
+    let answer = day.solve_part_streaming(Part::One, &mut input.as_bytes());
+    assert_eq!(answer.unwrap().unwrap(), "37327623");
+
+    let input = "1\n2\n3\n2024\n";
+    let answer = day.solve_part_streaming(Part::Two, &mut input.as_bytes());
+    assert_eq!(answer.unwrap().unwrap(), "23");
+}
+
+#[test]
+fn test_next_secret() {
+    let secrets: Vec<u64> = (0..3)
+        .scan(123, |secret, _| {
+            *secret = Buyer::next_secret(*secret);
+            Some(*secret)
+        })
+        .collect();
+    assert_eq!(secrets, vec![15887950, 16495136, 527345]);
+}
+
+#[test]
+fn test_too_few_iterations() {
+    let day = Day22 {
+        params: Day22Params { iterations: 3 },
+    };
+    assert_eq!(day.solve_part2("1\n2\n3\n2024\n").unwrap(), "0");
+
+    let answer = day.solve_part_streaming(Part::Two, &mut "1\n2\n3\n2024\n".as_bytes());
+    assert_eq!(answer.unwrap().unwrap(), "0");
+}
@@ -0,0 +1,42 @@
+use crate::utils::random::Rng;
+use std::collections::BTreeSet;
+
+// A network of `size` computers with a few random connections each, and a planted LAN party that is
+// larger than any group that formed by chance
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names: Vec<String> = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| String::from_utf8(vec![a, b]).unwrap()))
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(3, names.len()));
+
+    let mut connections = BTreeSet::new();
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            connections.insert((a.min(b), a.max(b)));
+        }
+    };
+
+    for a in 0..names.len() {
+        for _ in 0..2 {
+            connect(a, rng.below(names.len() as u64) as usize);
+        }
+    }
+
+    let party = names.len().clamp(3, 13);
+    for a in 0..party {
+        for b in a + 1..party {
+            connect(a, b);
+        }
+    }
+
+    let mut lines: Vec<String> = connections
+        .iter()
+        .map(|&(a, b)| match rng.chance(0.5) {
+            true => format!("{}-{}\n", names[a], names[b]),
+            false => format!("{}-{}\n", names[b], names[a]),
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
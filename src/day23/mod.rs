@@ -1,4 +1,6 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
+use crate::utils::parsing::{non_empty_lines, split_pair};
 use maplit::hashset;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
@@ -6,13 +8,14 @@ use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
+pub mod generate;
 #[cfg(test)]
 mod test;
 pub struct Day23 {}
 
 #[derive(Clone, PartialEq, Eq)]
-struct Computer {
-    name: String,
+pub struct Computer {
+    pub name: String,
     neighbors: RefCell<Vec<Rc<Computer>>>,
 }
 
@@ -35,7 +38,7 @@ impl Hash for Computer {
     }
 }
 
-struct LAN {
+pub struct LAN {
     computers: Vec<Rc<Computer>>,
 }
 
@@ -86,7 +89,7 @@ impl LAN {
         cycles
     }
 
-    fn find_cycles(&self, length: u32) -> HashSet<Vec<Rc<Computer>>> {
+    pub fn find_cycles(&self, length: u32) -> HashSet<Vec<Rc<Computer>>> {
         let mut cycles = HashSet::new();
         for node in self.computers.iter() {
             cycles.extend(self.walk(node, length - 1, vec![]))
@@ -120,56 +123,46 @@ impl LAN {
         cliques
     }
 
-    fn find_maximum_clique(&self) -> Vec<Rc<Computer>> {
+    pub fn find_maximum_clique(&self) -> Result<Vec<Rc<Computer>>, SolveError> {
         let cliques = self.bron_kerbosch(
             hashset!(),
             self.computers.iter().cloned().collect::<ComputerSet>(),
             hashset!(),
         );
 
-        let maximum_clique = cliques
-            .iter()
+        cliques
+            .into_iter()
+            .filter(|c| !c.is_empty())
             .max_by_key(|c| c.len())
-            .unwrap()
-            .iter()
-            .collect::<Vec<_>>();
-
-        maximum_clique.iter().map(|&c| c.clone()).collect()
+            .map(|c| c.into_iter().collect())
+            .ok_or_else(|| SolveError::no_solution("the network has no computers"))
     }
 }
 
-fn parse_input(input: &str) -> LAN {
+pub fn parse_input(input: &str) -> Result<LAN, SolveError> {
     let mut computers: HashMap<String, Rc<Computer>> = HashMap::new();
 
-    let string_connections: Vec<(String, String)> = input
-        .trim()
-        .lines()
-        .map(|l| l.trim())
-        .map(|l| l.split_once('-').unwrap())
-        .map(|(l, r)| (l.to_string(), r.to_string()))
-        .collect::<Vec<(String, String)>>();
+    let string_connections: Vec<(String, String)> = non_empty_lines(input)
+        .map(|l| split_pair(input, l, "-"))
+        .map(|pair| pair.map(|(l, r)| (l.trim().to_string(), r.trim().to_string())))
+        .collect::<Result<Vec<(String, String)>, SolveError>>()?;
 
-    for (left, right) in &string_connections {
-        computers
+    for (left, right) in string_connections {
+        let left = computers
             .entry(left.clone())
-            .or_insert_with(|| Computer::new(left.clone()));
-        computers
+            .or_insert_with(|| Computer::new(left))
+            .clone();
+        let right = computers
             .entry(right.clone())
-            .or_insert_with(|| Computer::new(right.clone()));
+            .or_insert_with(|| Computer::new(right))
+            .clone();
+        left.add_neighbor(right.clone());
+        right.add_neighbor(left);
     }
 
-    string_connections
-        .into_iter()
-        .for_each(|(left, right)| {
-            let left = computers.get(&left).unwrap();
-            let right = computers.get(&right).unwrap();
-            left.add_neighbor(right.clone());
-            right.add_neighbor(left.clone());
-        });
-
-    LAN {
+    Ok(LAN {
         computers: computers.values().cloned().collect(),
-    }
+    })
 }
 
 impl fmt::Display for LAN {
@@ -186,23 +179,27 @@ impl fmt::Display for LAN {
 }
 
 impl Solution for Day23 {
-    fn solve_part1(&self, input: &str) -> String {
-        let lan = parse_input(input);
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let lan = parse_input(input)?;
 
         let triples = lan.find_cycles(3);
-        triples
+        Ok(triples
             .iter()
             .filter(|l| l.iter().any(|c| c.name.starts_with("t")))
             .count()
-            .to_string()
+            .into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let lan = parse_input(input);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let lan = parse_input(input)?;
 
-        let maximum_clique = lan.find_maximum_clique();
+        let maximum_clique = lan.find_maximum_clique()?;
         let sorted = sort_computers(maximum_clique);
 
-        format!("{}", sorted.iter().map(|c| c.name.clone()).collect::<Vec<String>>().join(","))
+        Ok(sorted.iter().map(|c| c.name.as_str()).collect())
     }
 }
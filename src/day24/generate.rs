@@ -0,0 +1,136 @@
+use crate::utils::random::Rng;
+use std::collections::BTreeSet;
+
+struct Gate {
+    left: String,
+    right: String,
+    operation: &'static str,
+    output: String,
+}
+
+// The wires of a full adder that can be swapped while the circuit stays well formed
+#[derive(Clone, Copy)]
+enum Swap {
+    // The XOR and AND of the two input bits
+    Inputs,
+    // The output bit and the carry to the next bit
+    OutputCarry,
+    // The output bit and the AND of the partial sum with the incoming carry
+    OutputAnd,
+}
+
+fn wire_name(rng: &mut Rng, used: &mut BTreeSet<String>) -> String {
+    loop {
+        let name: String = (0..3)
+            .map(|i| match i {
+                0 => (b'a' + rng.range(0..=22)) as char,
+                _ => (b'a' + rng.range(0..=25)) as char,
+            })
+            .collect();
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // The puzzle swaps four pairs of gates, which takes at least five bits
+    adder(rng, size.max(5), 4).expect("four swaps fit in a five bit adder")
+}
+
+// A ripple-carry adder of two `bits` wide numbers, with the outputs of `swaps` pairs of gates swapped.
+// Every swap is in a different full adder, so the adder can be repaired by swapping them back.
+pub fn adder(rng: &mut Rng, bits: usize, swaps: usize) -> Result<String, String> {
+    let bits = bits.clamp(2, 62);
+    if swaps > bits - 1 {
+        return Err(format!(
+            "A {} bit adder fits at most {} swaps",
+            bits,
+            bits - 1
+        ));
+    }
+
+    // Internal names never start with x, y or z
+    let mut used = BTreeSet::new();
+    let mut gates: Vec<Gate> = vec![];
+    let mut gate = |left: &String, right: &String, operation, output: String| {
+        gates.push(Gate {
+            left: left.clone(),
+            right: right.clone(),
+            operation,
+            output,
+        });
+        gates.len() - 1
+    };
+
+    let mut swapped_bits: Vec<usize> = (1..bits).collect();
+    rng.shuffle(&mut swapped_bits);
+    swapped_bits.truncate(swaps);
+    let mut swapped = vec![];
+
+    let (x, y) = (format!("x{:02}", 0), format!("y{:02}", 0));
+    gate(&x, &y, "XOR", "z00".to_string());
+    let mut carry = wire_name(rng, &mut used);
+    gate(&x, &y, "AND", carry.clone());
+
+    for bit in 1..bits {
+        let (x, y, z) = (
+            format!("x{:02}", bit),
+            format!("y{:02}", bit),
+            format!("z{:02}", bit),
+        );
+        let sum = wire_name(rng, &mut used);
+        let and = wire_name(rng, &mut used);
+        let carry_and = wire_name(rng, &mut used);
+        let carry_out = match bit == bits - 1 {
+            true => format!("z{:02}", bits),
+            false => wire_name(rng, &mut used),
+        };
+
+        let sum_gate = gate(&x, &y, "XOR", sum.clone());
+        let and_gate = gate(&x, &y, "AND", and.clone());
+        let z_gate = gate(&sum, &carry, "XOR", z);
+        let carry_and_gate = gate(&sum, &carry, "AND", carry_and.clone());
+        let carry_out_gate = gate(&and, &carry_and, "OR", carry_out.clone());
+
+        if swapped_bits.contains(&bit) {
+            // Swapping the last carry would swap two output bits
+            let kinds: &[Swap] = match bit == bits - 1 {
+                true => &[Swap::Inputs, Swap::OutputAnd],
+                false => &[Swap::Inputs, Swap::OutputCarry, Swap::OutputAnd],
+            };
+            swapped.push(match rng.choose(kinds) {
+                Swap::Inputs => (sum_gate, and_gate),
+                Swap::OutputCarry => (z_gate, carry_out_gate),
+                Swap::OutputAnd => (z_gate, carry_and_gate),
+            });
+        }
+
+        carry = carry_out;
+    }
+
+    for (a, b) in swapped {
+        let output = gates[a].output.clone();
+        gates[a].output = std::mem::replace(&mut gates[b].output, output);
+    }
+
+    let initial_values: String = ["x", "y"]
+        .iter()
+        .flat_map(|wire| (0..bits).map(move |bit| (wire, bit)))
+        .map(|(wire, bit)| format!("{}{:02}: {}\n", wire, bit, rng.range(0..=1)))
+        .collect();
+
+    rng.shuffle(&mut gates);
+    let connections: String = gates
+        .iter()
+        .map(|gate| {
+            let (left, right) = match rng.chance(0.5) {
+                true => (&gate.left, &gate.right),
+                false => (&gate.right, &gate.left),
+            };
+            format!("{} {} {} -> {}\n", left, gate.operation, right, gate.output)
+        })
+        .collect();
+
+    Ok(initial_values + "\n" + &connections)
+}
@@ -0,0 +1,121 @@
+use crate::day24::{is_bit_wire, Gate, GateType, Wiring};
+use crate::error::SolveError;
+use crate::solution::Answer;
+use itertools::Itertools;
+use log::info;
+use std::collections::HashMap;
+
+fn apply(operation: &GateType, left: bool, right: bool) -> bool {
+    match operation {
+        GateType::AND => left && right,
+        GateType::XOR => left ^ right,
+        GateType::OR => left || right,
+    }
+}
+
+fn is_input(wire: &str) -> bool {
+    is_bit_wire(wire, 'x') || is_bit_wire(wire, 'y')
+}
+
+// The number formed by the wires starting with `letter`, `<letter>00` being the lowest bit
+fn number(letter: char, values: &HashMap<String, bool>) -> u64 {
+    values
+        .iter()
+        .filter(|(wire, _)| is_bit_wire(wire, letter))
+        .sorted()
+        .rev()
+        .fold(0, |number, (_, &bit)| number << 1 | bit as u64)
+}
+
+impl Wiring {
+    fn feeds(&self, wire: &str, operation: GateType) -> bool {
+        self.connections
+            .iter()
+            .any(|gate| (gate.left == wire || gate.right == wire) && gate.operation == operation)
+    }
+
+    // Checks a gate against its place in a ripple-carry adder, where bit `i` is computed as
+    //
+    //     partial = x_i XOR y_i        z_i = partial XOR carry_in
+    //     direct = x_i AND y_i         carry_out = direct OR (partial AND carry_in)
+    //
+    // and the last output bit is the final carry
+    fn is_misplaced(&self, gate: &Gate, last_bit: &str) -> bool {
+        let from_inputs = is_input(&gate.left) && is_input(&gate.right);
+        let first_bit = from_inputs && gate.left.ends_with("00");
+        let output_bit = is_bit_wire(&gate.output, 'z');
+
+        match gate.operation {
+            _ if gate.output == last_bit => gate.operation != GateType::OR,
+            GateType::XOR if first_bit => gate.output != "z00",
+            GateType::XOR if from_inputs => !self.feeds(&gate.output, GateType::XOR),
+            GateType::XOR => !output_bit,
+            // The first carry has no partial term, so it feeds the next bit directly
+            GateType::AND if first_bit => output_bit,
+            GateType::AND => !self.feeds(&gate.output, GateType::OR),
+            GateType::OR => output_bit,
+        }
+    }
+
+    // Evaluates the gates in dependency order, each pass evaluates the gates whose inputs are known
+    pub fn solve_z(&self) -> Result<Answer, SolveError> {
+        let mut values = self.initial_values.clone();
+        let mut pending: Vec<&Gate> = self.connections.iter().collect();
+
+        while !pending.is_empty() {
+            let remaining = pending.len();
+
+            pending.retain(|gate| {
+                let left = values.get(&gate.left).copied();
+                let right = values.get(&gate.right).copied();
+
+                match (left, right) {
+                    (Some(left), Some(right)) => {
+                        values.insert(gate.output.clone(), apply(&gate.operation, left, right));
+                        false
+                    }
+                    _ => true,
+                }
+            });
+
+            if pending.len() == remaining {
+                return Err(SolveError::no_solution(format!(
+                    "the inputs of {} gates are never set",
+                    remaining
+                )));
+            }
+        }
+
+        Ok(number('z', &values).into())
+    }
+
+    // Finds the swapped outputs by the structure of the adder instead of by searching, which
+    // recognizes swaps within a bit and between an output bit and the carry logic
+    pub fn solve_swap(&self) -> Result<Answer, SolveError> {
+        let last_bit = self
+            .connections
+            .iter()
+            .map(|gate| &gate.output)
+            .filter(|wire| is_bit_wire(wire, 'z'))
+            .max()
+            .ok_or_else(|| SolveError::no_solution("the circuit has no output bits"))?;
+
+        let swapped: Vec<&String> = self
+            .connections
+            .iter()
+            .filter(|gate| self.is_misplaced(gate, last_bit))
+            .map(|gate| &gate.output)
+            .sorted()
+            .collect();
+
+        if swapped.len() != 8 {
+            return Err(SolveError::no_solution(format!(
+                "found {} misplaced gate outputs, expected four swapped pairs",
+                swapped.len()
+            )));
+        }
+
+        info!("Misplaced outputs: {}", swapped.iter().join(", "));
+        Ok(swapped.into_iter().map(|wire| wire.as_str()).collect())
+    }
+}
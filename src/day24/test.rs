@@ -1,25 +1,85 @@
-use crate::day24::Day24;
+use crate::day24::{generate, is_bit_wire, parse_input, Day24, GateType, Wiring};
 use crate::solution::Solution;
+use crate::utils::random::Rng;
 
 #[test]
-fn test_part1() {
+fn test_part2() {
     let day = Day24 {};
-    assert_eq!(day.solve_part1(r#"
-    x00: 1
-    x01: 1
-    x02: 1
-    y00: 0
-    y01: 1
-    y02: 0
-    
-    x00 AND y00 -> z00
-    x01 XOR y01 -> z01
-    x02 OR y02 -> z02
-    "#), "4");
+    assert!(day.solve_part2("").is_err());
+}
+
+fn number(wiring: &Wiring, letter: char) -> u64 {
+    (0..64)
+        .filter(|bit| wiring.initial_values.get(&format!("{}{:02}", letter, bit)) == Some(&true))
+        .map(|bit| 1 << bit)
+        .sum()
 }
 
 #[test]
-fn test_part2() {
-    let day = Day24 {};
-    assert_eq!(day.solve_part2(""), "");
+fn test_adder() {
+    for seed in 0..5 {
+        let input = generate::adder(&mut Rng::new(seed), 20, 0).unwrap();
+        let wiring = parse_input(&input).unwrap();
+        let sum = number(&wiring, 'x') + number(&wiring, 'y');
+
+        assert_eq!(wiring.solve_z().unwrap(), sum.to_string().as_str());
+    }
+}
+
+#[test]
+fn test_swapped_adder() {
+    let input = generate::adder(&mut Rng::new(3), 12, 0).unwrap();
+    let mut wiring = parse_input(&input).unwrap();
+    let position = |wiring: &Wiring, output: &str| {
+        wiring
+            .connections
+            .iter()
+            .position(|gate| gate.output == output)
+            .unwrap()
+    };
+    let input_gate = |wiring: &Wiring, bit: usize, operation: GateType| {
+        wiring
+            .connections
+            .iter()
+            .position(|gate| gate.left[1..] == format!("{:02}", bit) && gate.operation == operation)
+            .unwrap()
+    };
+
+    // Swaps within a bit, and between output bits and the carry logic of their bit
+    let z04 = position(&wiring, "z04");
+    let z09 = position(&wiring, "z09");
+    let pairs = [
+        (
+            input_gate(&wiring, 2, GateType::XOR),
+            input_gate(&wiring, 2, GateType::AND),
+        ),
+        (
+            input_gate(&wiring, 6, GateType::XOR),
+            input_gate(&wiring, 6, GateType::AND),
+        ),
+        (z04, input_gate(&wiring, 4, GateType::AND)),
+        (z09, input_gate(&wiring, 10, GateType::XOR)),
+    ];
+
+    let mut expected = vec![];
+    for (a, b) in pairs {
+        let output = wiring.connections[a].output.clone();
+        wiring.connections[a].output = std::mem::replace(&mut wiring.connections[b].output, output);
+        expected.extend([
+            wiring.connections[a].output.clone(),
+            wiring.connections[b].output.clone(),
+        ]);
+    }
+    expected.sort();
+
+    assert_eq!(wiring.solve_swap().unwrap(), expected.join(",").as_str());
+}
+
+#[test]
+fn test_is_bit_wire() {
+    assert!(is_bit_wire("x00", 'x'));
+    assert!(is_bit_wire("z45", 'z'));
+    assert!(!is_bit_wire("x", 'x'));
+    assert!(!is_bit_wire("xkq", 'x'));
+    assert!(!is_bit_wire("y01", 'x'));
 }
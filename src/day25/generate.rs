@@ -0,0 +1,32 @@
+use crate::utils::random::Rng;
+
+// `size` schematics of locks and keys with pins of random heights
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let heights: Vec<usize> = (0..5).map(|_| rng.range(0..=5)).collect();
+            let lock = rng.chance(0.5);
+
+            (0..7)
+                .map(|row| {
+                    heights
+                        .iter()
+                        .map(|&height| {
+                            let filled = match lock {
+                                true => row <= height,
+                                false => row >= 6 - height,
+                            };
+                            if filled {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect::<String>()
+                        + "\n"
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,31 +1,34 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
 use crate::utils::nalgebra::MatrixParser;
+use crate::utils::parsing::sections;
 use itertools::Itertools;
 use nalgebra::DMatrix;
 
+pub mod generate;
 #[cfg(test)]
 mod test;
 pub struct Day25 {}
 
-type Schematic = DMatrix<u8>;
+pub type Schematic = DMatrix<u8>;
 
-struct LocksAndKeys {
+pub struct LocksAndKeys {
     locks: Vec<Schematic>,
     keys: Vec<Schematic>,
 }
 
 impl LocksAndKeys {
-    fn get_locks_and_keys(items: &Vec<Schematic>) -> Self {
+    pub fn get_locks_and_keys(items: &[Schematic]) -> Self {
         Self {
             locks: items
                 .iter()
-                .cloned()
                 .filter(|m| m.row(0).iter().all(|&value| value == 1))
+                .cloned()
                 .collect_vec(),
             keys: items
                 .iter()
-                .cloned()
                 .filter(|m| m.row(m.nrows() - 1).iter().all(|&value| value == 1))
+                .cloned()
                 .collect_vec(),
         }
     }
@@ -35,45 +38,58 @@ impl LocksAndKeys {
         sum.iter().all(|&value| value <= 1)
     }
 
-    fn count_fitting(&self) -> usize {
+    pub fn count_fitting(&self) -> usize {
         self.locks
             .iter()
             .map(|l| self.keys.iter().filter(|k| Self::fits(l, k)).count())
             .sum()
     }
 
-    fn new(items: &Vec<Schematic>) -> Self {
+    fn new(items: &[Schematic]) -> Self {
         Self::get_locks_and_keys(items)
     }
 }
 
-fn parse_input(input: &str) -> LocksAndKeys {
-    let normalized = input.trim().replace("\r\n", "\n");
-    let items = normalized
-        .split("\n\n")
-        .map(|e| e.trim().to_string())
-        .collect_vec();
-    let matrices = items
-        .iter()
-        .map(|e| {
-            e.to_matrix(|c| match c {
-                '.' => 0,
-                '#' => 1,
-                _ => panic!("Cannot parse {c}"),
+pub fn parse_input(input: &str) -> Result<LocksAndKeys, SolveError> {
+    let mut matrices: Vec<Schematic> = vec![];
+
+    for section in sections(input) {
+        let matrix = section
+            .try_to_matrix(|c| match c {
+                '.' => Some(0),
+                '#' => Some(1),
+                _ => None,
             })
-        })
-        .collect_vec();
+            .map_err(|e| e.within(input, section))?;
 
-    LocksAndKeys::new(&matrices)
+        if let Some(first) = matrices.first() {
+            if first.shape() != matrix.shape() {
+                return Err(SolveError::invalid_input(
+                    input,
+                    section,
+                    "all schematics must have the same size",
+                ));
+            }
+        }
+
+        matrices.push(matrix);
+    }
+
+    Ok(LocksAndKeys::new(&matrices))
 }
 
 impl Solution for Day25 {
-    fn solve_part1(&self, input: &str) -> String {
-        let locks_and_keys = parse_input(input);
-        locks_and_keys.count_fitting().to_string()
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let locks_and_keys = parse_input(input)?;
+        Ok(locks_and_keys.count_fitting().into())
     }
 
-    fn solve_part2(&self, _input: &str) -> String {
-        "".into()
+    fn solve_part2(&self, _input: &str) -> Result<Answer, SolveError> {
+        // There is no second puzzle on the last day
+        Ok(Answer::None)
     }
 }
@@ -0,0 +1,58 @@
+use crate::utils::random::Rng;
+
+static NOISE: &[&str] = &[
+    "what()",
+    "from()",
+    "select()",
+    "who()",
+    "#",
+    "%",
+    "&",
+    "*",
+    "+",
+    "-",
+    "<",
+    ">",
+    "[",
+    "]",
+    "{",
+    "}",
+    "@",
+    "^",
+    "~",
+    "'",
+    ":",
+    ";",
+    " ",
+    "?",
+    "!",
+    "mul(",
+    "mul[3,7]",
+    "mul(6,9!",
+    "mul ( 2 , 4 )",
+    "do_not_mul(5,5)",
+    "don't",
+    "do(",
+];
+
+// `size` instructions between corrupted characters, some multiplications are disabled
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut memory = String::new();
+
+    for _ in 0..size {
+        for _ in 0..rng.range(0..=3) {
+            memory += *rng.choose(NOISE);
+        }
+
+        let roll = rng.float();
+        if roll < 0.1 {
+            memory += "do()";
+        } else if roll < 0.2 {
+            memory += "don't()";
+        } else {
+            memory += &format!("mul({},{})", rng.range(1..=999), rng.range(1..=999));
+        }
+    }
+
+    memory + "\n"
+}
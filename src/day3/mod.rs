@@ -1,22 +1,23 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
+use crate::utils::parsing::parse_number;
 use regex::Regex;
 use std::collections::HashMap;
-#[cfg(test)]
-mod test;
+pub mod generate;
 pub struct Day3 {}
 
 static MUL_REGEX: &str = r"mul\((\d+),(\d+)\)";
 
-fn extract_values(input: &str, re: Regex) -> Vec<(i32, i32)> {
+fn extract_values(input: &str, re: Regex) -> Result<Vec<(i32, i32)>, SolveError> {
     let mut results: Vec<(i32, i32)> = vec![];
-    for (_, [left, right]) in re.captures_iter(input.trim()).map(|c| c.extract()) {
-        results.push((left.parse().unwrap(), right.parse().unwrap()));
+    for (_, [left, right]) in re.captures_iter(input).map(|c| c.extract()) {
+        results.push((parse_number(input, left)?, parse_number(input, right)?));
     }
 
-    results
+    Ok(results)
 }
 
-fn parse_input(input: &str) -> Vec<(i32, i32)> {
+pub fn parse_input(input: &str) -> Result<Vec<(i32, i32)>, SolveError> {
     let re = Regex::new(MUL_REGEX).unwrap();
     extract_values(input, re)
 }
@@ -27,15 +28,19 @@ enum Action {
 }
 
 impl Solution for Day3 {
-    fn solve_part1(&self, input: &str) -> String {
-        parse_input(input)
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(parse_input(input)?
             .iter()
             .map(|&(left, right)| left * right)
             .sum::<i32>()
-            .to_string()
+            .into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
         let mut action_map: HashMap<&str, Action> = HashMap::new();
 
         action_map.insert(r"do\(\)", Action::EnableMul);
@@ -44,43 +49,32 @@ impl Solution for Day3 {
 
         let mut actions = action_map
             .iter()
-            .map(|(needle, action)| {
+            .flat_map(|(needle, action)| {
                 let re = Regex::new(needle).unwrap();
-                return re
-                    .find_iter(input)
+                re.find_iter(input)
                     .map(|m| (action, m.as_str(), m.start()))
-                    .collect::<Vec<(&Action, &str, usize)>>();
+                    .collect::<Vec<(&Action, &str, usize)>>()
             })
-            .into_iter()
-            .flatten()
             .collect::<Vec<(&Action, &str, usize)>>();
         actions.sort_by_key(|&(_, _, p)| p);
 
         let mut enable = true;
         let mut sum = 0;
 
-        let mut apply_action = |action: &Action, s: &str| match action {
-            Action::EnableMul => enable = true,
-            Action::DisableMul => enable = false,
-            Action::ApplyMul => {
-                if enable {
-                    let re = Regex::new(MUL_REGEX).unwrap();
-                    let m = re.captures(s).unwrap();
-                    let left = m
-                        .get(1)
-                        .map(|m| m.as_str().parse::<i32>().unwrap())
-                        .unwrap();
-                    let right = m
-                        .get(2)
-                        .map(|m| m.as_str().parse::<i32>().unwrap())
-                        .unwrap();
-                    sum += left * right;
+        let re = Regex::new(MUL_REGEX).unwrap();
+        for (action, s, _) in actions {
+            match action {
+                Action::EnableMul => enable = true,
+                Action::DisableMul => enable = false,
+                Action::ApplyMul => {
+                    if enable {
+                        let (_, [left, right]) = re.captures(s).unwrap().extract();
+                        sum += parse_number::<i32>(input, left)? * parse_number::<i32>(input, right)?;
+                    }
                 }
             }
-        };
-
-        actions.iter().for_each(|(a, s, _)| apply_action(a, *s));
+        }
 
-        sum.to_string()
+        Ok(sum.into())
     }
 }
@@ -0,0 +1,16 @@
+use crate::generate::render_grid;
+use crate::utils::random::Rng;
+
+// A `size` by `size` word search of the letters of XMAS
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let grid: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *rng.choose(&['X', 'M', 'A', 'S']))
+                .collect()
+        })
+        .collect();
+
+    render_grid(&grid)
+}
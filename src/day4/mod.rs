@@ -1,32 +1,21 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
+use crate::utils::geometry::Direction8;
 use crate::utils::nalgebra::MatrixParser;
 use nalgebra::{DMatrix, Vector2};
+use strum::IntoEnumIterator;
 
-#[cfg(test)]
-mod test;
+pub mod generate;
 pub struct Day4 {}
 
-fn parse_input(input: &str) -> DMatrix<char> {
-    input.to_string().to_matrix(|c| c)
+pub fn parse_input(input: &str) -> Result<DMatrix<char>, SolveError> {
+    input.try_to_matrix(Some)
 }
 
-fn get_directions(offsets_x: Vec<i32>, offsets_y: Vec<i32>) -> Vec<Vector2<i32>> {
-    offsets_x
-        .iter()
-        .map(|&x| {
-            offsets_y
-                .iter()
-                .map(|&y| Vector2::new(x, y))
-                .collect::<Vec<Vector2<i32>>>()
-        })
-        .flatten()
-        .collect()
-}
-
-fn count_occurrences(
+pub fn count_occurrences(
     matrix: &DMatrix<char>,
     location: Vector2<i32>,
-    directions: &Vec<Vector2<i32>>,
+    directions: &[Vector2<i32>],
     needle: &str,
 ) -> u32 {
     if location.x < 0 || location.y < 0 {
@@ -51,7 +40,7 @@ fn count_occurrences(
     let mut sum = 0;
     for d in directions {
         let new_loc: Vector2<i32> = location + d;
-        let fixed_direction = vec![d.clone()];
+        let fixed_direction = vec![*d];
 
         let mut remaining_needle = needle.to_string();
         remaining_needle.remove(0);
@@ -63,10 +52,14 @@ fn count_occurrences(
 }
 
 impl Solution for Day4 {
-    fn solve_part1(&self, input: &str) -> String {
-        let matrix = parse_input(input);
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let matrix = parse_input(input)?;
 
-        let directions = get_directions(vec![-1, 0, 1], vec![-1, 0, 1]);
+        let directions: Vec<Vector2<i32>> = Direction8::iter().map(|d| d.to_vector()).collect();
 
         let mut sum: u32 = 0;
         for row in 0..matrix.nrows() {
@@ -76,25 +69,23 @@ impl Solution for Day4 {
             }
         }
 
-        sum.to_string()
+        Ok(sum.into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let matrix = parse_input(input);
-        let diagonals = get_directions(vec![-1, 1], vec![-1, 1]);
-        let diagonal_groups = vec![
-            get_directions(vec![1], vec![-1, 1]),
-            get_directions(vec![-1], vec![-1, 1]),
-            get_directions(vec![-1, 1], vec![1]),
-            get_directions(vec![-1, 1], vec![-1]),
-        ];
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let matrix = parse_input(input)?;
+        let diagonals: Vec<Vector2<i32>> = Direction8::diagonals().map(|d| d.to_vector()).collect();
+        // The two diagonals on one side of the 'A', where both 'S' of the cross are
+        let diagonal_groups: Vec<Vec<Vector2<i32>>> = Direction8::diagonals()
+            .map(|d| vec![d.to_vector(), d.clockwise().clockwise().to_vector()])
+            .collect();
 
         let mut sum: u32 = 0;
         for row in 0..matrix.nrows() {
             for col in 0..matrix.ncols() {
                 let loc = Vector2::new(row as i32, col as i32);
                 for d in &diagonal_groups {
-                    let count_s = count_occurrences(&matrix, loc, &d, "AS");
+                    let count_s = count_occurrences(&matrix, loc, d, "AS");
                     if count_s != 2 {
                         continue;
                     }
@@ -107,6 +98,6 @@ impl Solution for Day4 {
             }
         }
 
-        sum.to_string()
+        Ok(sum.into())
     }
 }
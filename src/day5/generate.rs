@@ -0,0 +1,36 @@
+use crate::utils::random::Rng;
+
+// Rules ordering every pair of pages, followed by `size` updates of which about half are ordered
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut pages: Vec<u32> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules = vec![];
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            rules.push(format!("{}|{}\n", before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let updates = (0..size).map(|_| {
+        let length = rng.range(2..=11) * 2 + 1;
+        let mut indices: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut indices);
+        indices.truncate(length);
+
+        if rng.chance(0.5) {
+            indices.sort();
+        }
+
+        indices
+            .iter()
+            .map(|&index| pages[index].to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+            + "\n"
+    });
+
+    rules.concat() + "\n" + &updates.collect::<String>()
+}
@@ -1,106 +1,107 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
+use crate::utils::parsing::{expect_sections, non_empty_lines, parse_number, split_pair};
 
-#[cfg(test)]
-mod test;
+pub mod generate;
 pub struct Day5 {}
 
 #[derive(Debug)]
-struct PageData {
-    ordering_rules: Vec<(u32, u32)>,
-    updates: Vec<Vec<u32>>,
+pub struct PageData {
+    pub ordering_rules: Vec<(u32, u32)>,
+    pub updates: Vec<Vec<u32>>,
 }
 
-fn parse_ordering_rules(input: &str) -> Vec<(u32, u32)> {
-    input
-        .lines()
-        .map(|line| line.split_once('|').unwrap())
-        .map(|(a, b)| (a.parse().unwrap(), b.parse().unwrap()))
+fn parse_ordering_rules(input: &str, section: &str) -> Result<Vec<(u32, u32)>, SolveError> {
+    non_empty_lines(section)
+        .map(|line| {
+            let (a, b) = split_pair(input, line, "|")?;
+            Ok((parse_number(input, a)?, parse_number(input, b)?))
+        })
         .collect()
 }
 
-fn parse_update_pages(input: &str) -> Vec<Vec<u32>> {
-    input
-        .lines()
-        .map(|line| line.split(',').map(|e| e.parse::<u32>().unwrap()).collect())
+fn parse_update_pages(input: &str, section: &str) -> Result<Vec<Vec<u32>>, SolveError> {
+    non_empty_lines(section)
+        .map(|line| line.split(',').map(|e| parse_number(input, e)).collect())
         .collect()
 }
 
-fn parse_input(input: &str) -> PageData {
-    let normalized = input.replace("\r\n", "\n");
-    let lines: Vec<_> = normalized.lines().map(|l| l.trim()).collect();
-    let processed_input = lines.join("\n");
-    let mut parts = processed_input.split("\n\n");
+pub fn parse_input(input: &str) -> Result<PageData, SolveError> {
+    let [raw_ordering_rules, raw_update_pages] = expect_sections(input)?;
 
-    let raw_ordering_rules = parts.next().unwrap().trim();
-    let raw_update_pages = parts.next().unwrap().trim();
-
-    let data = PageData {
-        ordering_rules: parse_ordering_rules(raw_ordering_rules),
-        updates: parse_update_pages(raw_update_pages),
-    };
-
-    data
+    Ok(PageData {
+        ordering_rules: parse_ordering_rules(input, raw_ordering_rules)?,
+        updates: parse_update_pages(input, raw_update_pages)?,
+    })
 }
 
-fn satisfies_ordering_rule(update: &Vec<u32>, rule: &(u32, u32)) -> bool {
-    if !update.contains(&rule.0) || !update.contains(&rule.1) {
-        return true;
-    }
+// The indices of both pages of a rule that is broken by the update
+fn broken_rule_indices(update: &[u32], rule: &(u32, u32)) -> Option<(usize, usize)> {
+    let left_index = update.iter().position(|&n| n == rule.0)?;
+    let right_index = update.iter().position(|&n| n == rule.1)?;
 
-    let left_index = update.iter().position(|&n| n == rule.0).unwrap();
-    let right_index = update.iter().position(|&n| n == rule.1).unwrap();
+    (left_index > right_index).then_some((left_index, right_index))
+}
 
-    left_index < right_index
+fn satisfies_ordering_rule(update: &[u32], rule: &(u32, u32)) -> bool {
+    broken_rule_indices(update, rule).is_none()
 }
 
-fn update_satisfies_ordering_rules(update: &Vec<u32>, rules: &Vec<(u32, u32)>) -> bool {
+pub fn update_satisfies_ordering_rules(update: &[u32], rules: &[(u32, u32)]) -> bool {
     rules.iter().all(|r| satisfies_ordering_rule(update, r))
 }
 
-fn create_update_for_rules(update: &Vec<u32>, rules: &Vec<(u32, u32)>) -> Vec<u32> {
-    let mut new_update = update.clone();
+pub fn create_update_for_rules(update: &[u32], rules: &[(u32, u32)]) -> Vec<u32> {
+    let mut new_update = update.to_vec();
 
-    while let Some((left, right)) = rules
+    while let Some((left_index, right_index)) = rules
         .iter()
-        .find(|r| !satisfies_ordering_rule(&new_update, r))
+        .find_map(|r| broken_rule_indices(&new_update, r))
     {
-        let left_index = new_update.iter().position(|&n| n == *left).unwrap();
-        let right_index = new_update.iter().position(|&n| n == *right).unwrap();
         new_update.swap(left_index, right_index);
     }
 
     new_update
 }
 
-fn get_middle_number(update: &Vec<u32>) -> u32 {
-    if update.len() % 2 == 0 {
-        panic!("Found update page with even length, cannot find middle number")
+pub fn get_middle_number(update: &[u32]) -> Result<u32, SolveError> {
+    if update.len().is_multiple_of(2) {
+        return Err(SolveError::no_solution(format!(
+            "found update {:?} with even length, cannot find middle number",
+            update
+        )));
     }
 
-    update[(update.len() - 1) / 2]
+    Ok(update[(update.len() - 1) / 2])
 }
 
 impl Solution for Day5 {
-    fn solve_part1(&self, input: &str) -> String {
-        let data = parse_input(input);
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let data = parse_input(input)?;
 
-        data.updates
+        Ok(data
+            .updates
             .iter()
             .filter(|&p| update_satisfies_ordering_rules(p, &data.ordering_rules))
-            .map(|p| get_middle_number(&p))
-            .sum::<u32>()
-            .to_string()
+            .map(|p| get_middle_number(p))
+            .sum::<Result<u32, SolveError>>()?
+            .into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let data = parse_input(input);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let data = parse_input(input)?;
 
-        data.updates
+        Ok(data
+            .updates
             .iter()
             .filter(|&p| !update_satisfies_ordering_rules(p, &data.ordering_rules))
             .map(|p| create_update_for_rules(p, &data.ordering_rules))
             .map(|p| get_middle_number(&p))
-            .sum::<u32>()
-            .to_string()
+            .sum::<Result<u32, SolveError>>()?
+            .into())
     }
 }
@@ -0,0 +1,28 @@
+use crate::day6::{parse_input, walk_grid};
+use crate::generate::render_grid;
+use crate::utils::random::Rng;
+
+// A `size` by `size` lab the guard walks out of, after visiting at least `size` positions
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+
+    loop {
+        let mut grid: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.chance(0.05) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+
+        let row = rng.below(size as u64) as usize;
+        let col = rng.below(size as u64) as usize;
+        grid[row][col] = '^';
+
+        let input = render_grid(&grid);
+        let field = parse_input(&input).expect("generated labs are valid");
+        if walk_grid(&field).is_some_and(|positions| positions.len() >= size) {
+            return input;
+        }
+    }
+}
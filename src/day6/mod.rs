@@ -1,73 +1,60 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
 use crate::utils::geometry::Direction;
-use crate::utils::nalgebra::MatrixParser;
-use nalgebra::{vector, DMatrix, Vector2};
+use crate::utils::grid::{Grid, Tile};
+use crate::utils::render::{self, Canvas, Color, Colored, Frame, Image, Recorder};
+use nalgebra::Vector2;
 use once_cell::sync::Lazy;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 
-#[cfg(test)]
-mod test;
+pub mod generate;
 pub struct Day6 {}
 
 #[derive(Clone, PartialEq, Debug)]
-enum TileType {
+pub enum TileType {
     Wall,
     Floor,
     Start,
 }
 
-impl fmt::Display for TileType {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+impl Tile for TileType {
+    fn to_char(&self) -> char {
         match self {
-            TileType::Wall => fmt.write_str("#"),
-            TileType::Floor => fmt.write_str("."),
-            TileType::Start => fmt.write_str("^"),
+            TileType::Wall => '#',
+            TileType::Floor => '.',
+            TileType::Start => '^',
+        }
+    }
+}
+
+impl Colored for TileType {
+    fn color(&self) -> Color {
+        match self {
+            TileType::Wall => render::GRAY,
+            TileType::Floor => render::WHITE,
+            TileType::Start => render::RED,
         }
     }
 }
 
 static TILE_TYPE_STRING: Lazy<HashMap<char, TileType>> = Lazy::new(|| {
-    [
-        (
-            TileType::Wall.to_string().chars().next().unwrap(),
-            TileType::Wall,
-        ),
-        (
-            TileType::Floor.to_string().chars().next().unwrap(),
-            TileType::Floor,
-        ),
-        (
-            TileType::Start.to_string().chars().next().unwrap(),
-            TileType::Start,
-        ),
-    ]
-    .iter()
-    .cloned()
-    .collect()
+    [TileType::Wall, TileType::Floor, TileType::Start]
+        .into_iter()
+        .map(|tile| (tile.to_char(), tile))
+        .collect()
 });
 
 impl fmt::Display for Guard {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        for row in 0..self.field.nrows() {
-            for col in 0..self.field.ncols() {
-                if self
-                    .history
-                    .iter()
-                    .map(|h| h.position)
-                    .collect::<Vec<_>>()
-                    .contains(&Vector2::new(col as i32, row as i32))
-                {
-                    write!(fmt, "@")?;
-                } else {
-                    let tile = self.field.get((row, col)).unwrap();
-                    write!(fmt, "{}", tile)?;
-                }
-            }
-            write!(fmt, "\n")?;
-        }
-
-        Ok(())
+        let canvas = Canvas::from_colored_grid(&self.field).with_highlight(
+            self.history.iter().map(|h| h.position),
+            '@',
+            render::BLUE,
+            "visited",
+        );
+
+        write!(fmt, "{}", canvas)
     }
 }
 
@@ -77,57 +64,75 @@ struct Location {
     direction: Direction,
 }
 
-fn to_field_index(location: &Vector2<i32>) -> (usize, usize) {
-    (location.y as usize, location.x as usize)
-}
-
-fn is_on_field(field: &DMatrix<TileType>, location: &Vector2<i32>) -> bool {
-    field.get(to_field_index(location)).is_some()
+struct Guard {
+    field: Grid<TileType>,
+    history: HashSet<Location>,
 }
 
-fn find_position<T: PartialEq>(matrix: &DMatrix<T>, target: &T) -> Option<Vector2<i32>> {
-    for row in 0..matrix.nrows() {
-        for col in 0..matrix.ncols() {
-            if matrix[(row, col)] == *target {
-                return Some(vector![col as i32, row as i32]);
-            }
+impl Guard {
+    // The field with the visited positions marked 'X' and the guard facing its direction
+    fn frame(&self, location: &Location) -> Frame {
+        let mut map = self.field.map(|t| t.to_char());
+        for visited in &self.history {
+            map[visited.position] = 'X';
         }
+        map[location.position] = match location.direction {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        };
+
+        let image = Image::from_grid(&map, |c| match c {
+            '#' => render::GRAY,
+            '.' => render::WHITE,
+            'X' => render::BLUE,
+            _ => render::RED,
+        });
+        Frame::new(image, map.to_string())
     }
-    None
 }
 
-struct Guard {
-    field: DMatrix<TileType>,
-    history: HashSet<Location>,
+pub fn walk_grid(field: &Grid<TileType>) -> Option<HashSet<Vector2<i32>>> {
+    walk_grid_recorded(field, &mut Recorder::disabled())
 }
 
-fn walk_grid(field: &DMatrix<TileType>) -> Option<HashSet<Vector2<i32>>> {
+// Walks the guard like `walk_grid`, pushing a frame for every step it takes
+pub fn walk_grid_recorded(
+    field: &Grid<TileType>,
+    recorder: &mut Recorder,
+) -> Option<HashSet<Vector2<i32>>> {
     let mut guard = Guard {
         field: field.clone(),
         history: HashSet::new(),
     };
 
-    let mut guard_position = find_position(&guard.field, &TileType::Start).unwrap();
+    let mut guard_position = guard.field.find(&TileType::Start).unwrap();
     let mut guard_direction = Direction::Up;
 
-    guard.history.insert(Location {
+    let start = Location {
         position: guard_position,
         direction: guard_direction.clone(),
-    });
+    };
+    recorder.push(|| guard.frame(&start));
+    guard.history.insert(start);
 
     let mut new_position = guard_position + guard_direction.to_vector();
 
-    while is_on_field(&guard.field, &new_position) {
-        if guard.field[to_field_index(&new_position)] == TileType::Wall {
+    while let Some(tile) = guard.field.get(&new_position) {
+        if *tile == TileType::Wall {
             guard_direction = guard_direction.clockwise();
         } else {
             guard_position = new_position;
         }
 
-        if !guard.history.insert(Location {
+        let location = Location {
             position: guard_position,
             direction: guard_direction.clone(),
-        }) {
+        };
+        recorder.push(|| guard.frame(&location));
+
+        if !guard.history.insert(location) {
             return None;
         }
 
@@ -137,37 +142,58 @@ fn walk_grid(field: &DMatrix<TileType>) -> Option<HashSet<Vector2<i32>>> {
     let unique_positions: HashSet<Vector2<i32>> =
         guard.history.iter().map(|l| l.position).collect();
 
-    // println!("{}", guard);
-
     Some(unique_positions)
 }
 
-fn parse_input(input: &str) -> DMatrix<TileType> {
-    input
-        .to_string()
-        .to_matrix(|c| TILE_TYPE_STRING[&c].clone())
+pub fn parse_input(input: &str) -> Result<Grid<TileType>, SolveError> {
+    let (field, markers) =
+        Grid::parse_with_markers(input, &['^'], |c| TILE_TYPE_STRING.get(&c).cloned())?;
+
+    if markers.first('^').is_none() {
+        return Err(SolveError::invalid_input(
+            input,
+            input.trim(),
+            "the map does not contain a guard ('^')",
+        ));
+    }
+
+    Ok(field)
 }
 
 impl Solution for Day6 {
-    fn solve_part1(&self, input: &str) -> String {
-        let field = parse_input(input);
-        walk_grid(&field).unwrap().len().to_string()
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    // The guard walking its patrol route until it leaves the map or walks in a loop
+    fn record(&self, input: &str, recorder: &mut Recorder) -> Option<Result<(), SolveError>> {
+        Some(parse_input(input).map(|field| {
+            walk_grid_recorded(&field, recorder);
+        }))
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let field = parse_input(input)?;
+        let positions = walk_grid(&field)
+            .ok_or_else(|| SolveError::no_solution("the guard walks in a loop"))?;
+
+        Ok(positions.len().into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let field = parse_input(input);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let field = parse_input(input)?;
         let initial_positions = walk_grid(&field).unwrap_or_default();
 
-        initial_positions
+        Ok(initial_positions
             .iter()
-            .filter(|p| field[to_field_index(p)] == TileType::Floor)
+            .filter(|p| field[**p] == TileType::Floor)
             .filter(|p| {
                 let mut modified_grid = field.clone();
-                modified_grid[to_field_index(p)] = TileType::Wall;
+                modified_grid[**p] = TileType::Wall;
 
                 walk_grid(&modified_grid).is_none()
             })
             .count()
-            .to_string()
+            .into())
     }
 }
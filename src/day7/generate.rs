@@ -0,0 +1,26 @@
+use crate::utils::random::Rng;
+
+// `size` equations, most of them solvable. Operands are kept small, so no combination overflows.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let operands: Vec<u64> = (0..rng.range(2..=8)).map(|_| rng.range(1..=99)).collect();
+            let mut target = operands[0];
+
+            for &operand in &operands[1..] {
+                target = match rng.range(0..=2) {
+                    0 => target + operand,
+                    1 => target * operand,
+                    _ => target * 10u64.pow(operand.ilog10() + 1) + operand,
+                };
+            }
+
+            if rng.chance(0.3) {
+                target += 1;
+            }
+
+            let operands: Vec<String> = operands.iter().map(|o| o.to_string()).collect();
+            format!("{}: {}\n", target, operands.join(" "))
+        })
+        .collect()
+}
@@ -1,57 +1,65 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
+use crate::utils::parsing::{non_empty_lines, parse_number, split_pair};
 use maplit::hashmap;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 
-#[cfg(test)]
-mod test;
+pub mod generate;
 pub struct Day7 {}
 
-struct Equation {
-    target: u64,
-    operands: Vec<u64>,
+pub struct Equation {
+    pub target: u64,
+    pub operands: Vec<u64>,
 }
 
-fn parse_input(input: &str) -> Vec<Equation> {
-    input
-        .trim()
-        .lines()
+pub fn parse_input(input: &str) -> Result<Vec<Equation>, SolveError> {
+    non_empty_lines(input)
         .map(|l| {
-            let (target, operands) = l.split_once(':').unwrap();
-            Equation {
-                target: target.trim().parse::<u64>().unwrap(),
-                operands: operands
-                    .trim()
-                    .split(' ')
-                    .map(|x| x.trim().parse::<u64>().unwrap())
-                    .collect(),
+            let (target, operands) = split_pair(input, l, ":")?;
+            let operands = operands
+                .split_whitespace()
+                .map(|x| parse_number::<u64>(input, x))
+                .collect::<Result<Vec<u64>, SolveError>>()?;
+
+            if operands.is_empty() {
+                return Err(SolveError::invalid_input(input, l, "equation has no operands"));
             }
+
+            Ok(Equation {
+                target: parse_number(input, target)?,
+                operands,
+            })
         })
         .collect()
 }
 
 #[derive(Eq, PartialEq, Hash, Debug)]
-enum Operation {
+pub enum Operation {
     Add,
     Multiply,
     Concatenate,
 }
 
-static OPERATIONS: Lazy<HashMap<Operation, fn(&u64, &u64) -> u64>> = Lazy::new(|| {
+fn concatenate(a: &u64, b: &u64) -> Option<u64> {
+    let digits = b.checked_ilog10().unwrap_or(0) + 1;
+    10u64.checked_pow(digits)?.checked_mul(*a)?.checked_add(*b)
+}
+
+// Results that overflow are `None`, they are larger than any target
+static OPERATIONS: Lazy<HashMap<Operation, fn(&u64, &u64) -> Option<u64>>> = Lazy::new(|| {
     hashmap! {
-        Operation::Add => (|a: &u64, b: &u64| a + b) as fn(&u64, &u64) -> u64,
-        Operation::Multiply => (|a: &u64, b: &u64| a * b) as fn(&u64, &u64) -> u64,
-        Operation::Concatenate => (|a: &u64, b: &u64| a * 10u64.pow(b.ilog10() + 1) + b) as fn(&u64, &u64) -> u64,
+        Operation::Add => (|a: &u64, b: &u64| a.checked_add(*b)) as fn(&u64, &u64) -> Option<u64>,
+        Operation::Multiply => (|a: &u64, b: &u64| a.checked_mul(*b)) as fn(&u64, &u64) -> Option<u64>,
+        Operation::Concatenate => concatenate as fn(&u64, &u64) -> Option<u64>,
     }
 });
 
-fn check_equation_solvable(equation: &Equation, allow_concatenate: bool) -> bool {
-    if equation.operands.len() == 1 {
-        return equation.operands[0] == equation.target;
-    }
-
-    let [first, second, rest @ ..] = &equation.operands[..] else {
-        panic!("Could not destruct operands")
+pub fn check_equation_solvable(equation: &Equation, allow_concatenate: bool) -> bool {
+    let (first, second, rest) = match &equation.operands[..] {
+        [] => return false,
+        [only] => return *only == equation.target,
+        [first, second, rest @ ..] => (first, second, rest),
     };
 
     if first > &equation.target {
@@ -65,7 +73,9 @@ fn check_equation_solvable(equation: &Equation, allow_concatenate: bool) -> bool
         }
 
         let operation = OPERATIONS.get(operation_type).unwrap();
-        let new_operand = operation(first, second);
+        let Some(new_operand) = operation(first, second) else {
+            continue;
+        };
 
         let mut new_operands = vec![new_operand];
         new_operands.extend(rest);
@@ -82,23 +92,27 @@ fn check_equation_solvable(equation: &Equation, allow_concatenate: bool) -> bool
 }
 
 impl Solution for Day7 {
-    fn solve_part1(&self, input: &str) -> String {
-        let equations = parse_input(input);
-        equations
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let equations = parse_input(input)?;
+        Ok(equations
             .into_iter()
-            .filter(|eq| check_equation_solvable(&eq, false))
+            .filter(|eq| check_equation_solvable(eq, false))
             .map(|eq| eq.target)
             .sum::<u64>()
-            .to_string()
+            .into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let equations = parse_input(input);
-        equations
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let equations = parse_input(input)?;
+        Ok(equations
             .into_iter()
-            .filter(|eq| check_equation_solvable(&eq, true))
+            .filter(|eq| check_equation_solvable(eq, true))
             .map(|eq| eq.target)
             .sum::<u64>()
-            .to_string()
+            .into())
     }
 }
@@ -0,0 +1,20 @@
+use crate::generate::render_grid;
+use crate::utils::random::Rng;
+
+static FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// A `size` by `size` map with a few antennas for each frequency
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let frequencies: Vec<char> = FREQUENCIES.chars().collect();
+    let frequencies = &frequencies[..(size / 2).clamp(1, frequencies.len())];
+
+    let mut grid = vec![vec!['.'; size]; size];
+    for _ in 0..(size * size / 12).max(2) {
+        let row = rng.below(size as u64) as usize;
+        let col = rng.below(size as u64) as usize;
+        grid[row][col] = *rng.choose(frequencies);
+    }
+
+    render_grid(&grid)
+}
@@ -1,45 +1,33 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
 use nalgebra::Vector2;
 use std::collections::{HashMap, HashSet};
 
-#[cfg(test)]
-mod test;
+pub mod generate;
 pub struct Day8 {}
 
-fn get_bounds(input: &str) -> (usize, usize) {
-    let lines = input.trim().lines().collect::<Vec<&str>>();
-    (lines[0].trim().len(), lines.len())
+pub fn parse_input(input: &str) -> Result<Grid<char>, SolveError> {
+    Grid::parse(input, |c| {
+        (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+    })
 }
 
-fn parse_input(input: &str) -> HashMap<char, Vec<Vector2<i32>>> {
-    let lines: Vec<&str> = input.trim().lines().collect();
+pub fn find_antennas(map: &Grid<char>) -> HashMap<char, Vec<Vector2<i32>>> {
+    let mut antennas = HashMap::<char, Vec<Vector2<i32>>>::new();
 
-    let mut map = HashMap::<char, Vec<Vector2<i32>>>::new();
-
-    for y in 0..lines.len() {
-        for x in 0..lines[y].len() {
-            let char: char = lines[y].trim().chars().nth(x).unwrap();
-
-            if char != '.' {
-                map.entry(char)
-                    .or_insert_with(Vec::new)
-                    .push(Vector2::new(x as i32, y as i32));
-            }
-        }
+    for (position, &frequency) in map.iter().filter(|(_, &c)| c != '.') {
+        antennas.entry(frequency).or_default().push(position);
     }
 
-    map
-}
-
-fn is_inside(v: Vector2<i32>, bounds: (usize, usize)) -> bool {
-    v.x >= 0 && v.y >= 0 && v.x < bounds.0 as i32 && v.y < bounds.1 as i32
+    antennas
 }
 
 fn find_antinodes_directional(
     a: Vector2<i32>,
     b: Vector2<i32>,
     resonant: bool,
-    bounds: (usize, usize),
+    map: &Grid<char>,
 ) -> HashSet<Vector2<i32>> {
     let mut antinodes = HashSet::<Vector2<i32>>::new();
 
@@ -47,24 +35,22 @@ fn find_antinodes_directional(
     let mut node = b + a_to_b;
     if resonant {
         antinodes.insert(b);
-        while is_inside(node, bounds) {
+        while map.contains(&node) {
             antinodes.insert(node);
 
-            node = node + a_to_b;
-        }
-    } else {
-        if is_inside(node, bounds) {
-            antinodes.insert(node);
+            node += a_to_b;
         }
+    } else if map.contains(&node) {
+        antinodes.insert(node);
     }
 
     antinodes
 }
 
-fn find_antinodes(
-    antennas: &Vec<Vector2<i32>>,
+pub fn find_antinodes(
+    antennas: &[Vector2<i32>],
     resonant: bool,
-    bounds: (usize, usize),
+    map: &Grid<char>,
 ) -> HashSet<Vector2<i32>> {
     let mut antinodes = HashSet::<Vector2<i32>>::new();
 
@@ -72,22 +58,22 @@ fn find_antinodes(
         for j in i + 1..antennas.len() {
             let a = antennas[i];
             let b = antennas[j];
-            antinodes.extend(find_antinodes_directional(a, b, resonant, bounds));
-            antinodes.extend(find_antinodes_directional(b, a, resonant, bounds));
+            antinodes.extend(find_antinodes_directional(a, b, resonant, map));
+            antinodes.extend(find_antinodes_directional(b, a, resonant, map));
         }
     }
 
     antinodes
 }
 
-fn count_unique_antinodes(
+pub fn count_unique_antinodes(
     antennas: &HashMap<char, Vec<Vector2<i32>>>,
     resonant: bool,
-    bounds: (usize, usize),
+    map: &Grid<char>,
 ) -> usize {
     antennas
         .values()
-        .map(|nodes| find_antinodes(nodes, resonant, bounds))
+        .map(|nodes| find_antinodes(nodes, resonant, map))
         .collect::<Vec<HashSet<Vector2<i32>>>>()
         .into_iter()
         .fold(HashSet::new(), |mut acc, set| {
@@ -98,17 +84,21 @@ fn count_unique_antinodes(
 }
 
 impl Solution for Day8 {
-    fn solve_part1(&self, input: &str) -> String {
-        let antennas = parse_input(input);
-        let bounds = get_bounds(input);
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let map = parse_input(input)?;
+        let antennas = find_antennas(&map);
 
-        count_unique_antinodes(&antennas, false, bounds).to_string()
+        Ok(count_unique_antinodes(&antennas, false, &map).into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let antennas = parse_input(input);
-        let bounds = get_bounds(input);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let map = parse_input(input)?;
+        let antennas = find_antennas(&map);
 
-        count_unique_antinodes(&antennas, true, bounds).to_string()
+        Ok(count_unique_antinodes(&antennas, true, &map).into())
     }
 }
@@ -0,0 +1,13 @@
+use crate::utils::random::Rng;
+
+// A disk map of `size` digits, alternating between files and free space
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|i| match i % 2 {
+            0 => rng.range(1..=9),
+            _ => rng.range(0..=9),
+        })
+        .map(|digit: u32| char::from_digit(digit, 10).unwrap())
+        .collect::<String>()
+        + "\n"
+}
@@ -1,8 +1,12 @@
-use crate::solution::Solution;
+use crate::error::SolveError;
+use crate::solution::{Answer, Solution};
 use std::cmp::min;
 use std::fmt;
 pub use std::fmt::Write;
 
+pub mod generate;
+#[cfg(test)]
+mod oracle;
 #[cfg(test)]
 mod test;
 pub struct Day9 {}
@@ -15,7 +19,7 @@ struct Block {
 }
 
 impl Block {
-    fn checksum(&self) -> u64 {
+    pub fn checksum(&self) -> u64 {
         let indices_sum: u64 = (self.index..(self.index + self.size as u32))
             .map(|i| i as u64)
             .sum();
@@ -25,7 +29,7 @@ impl Block {
 }
 
 #[derive(Clone)]
-struct Disk {
+pub struct Disk {
     blocks: Vec<Block>,
 }
 impl Disk {
@@ -60,7 +64,7 @@ impl Disk {
         &mut self,
         block: Block,
         allow_fragmentation: bool,
-        max_index: usize,
+        mut max_index: usize,
     ) -> usize {
         let mut remaining_size: usize = block.size;
 
@@ -68,6 +72,8 @@ impl Disk {
             let min_block_size = if allow_fragmentation { 1 } else { block.size };
             if let Some((new_block, max_size)) = self.insert_empty_block(min_block_size, max_index)
             {
+                // The inserted block shifts the block being moved
+                max_index += 1;
                 new_block.file_id = block.file_id;
                 if remaining_size <= max_size {
                     new_block.size = remaining_size;
@@ -84,7 +90,7 @@ impl Disk {
         0
     }
 
-    fn compact(&mut self, allow_fragmentation: bool) {
+    pub fn compact(&mut self, allow_fragmentation: bool) {
         if allow_fragmentation {
             let mut compacted_last_block = true;
             while compacted_last_block {
@@ -107,13 +113,13 @@ impl Disk {
                 if remaining_size == 0 {
                     self.blocks.remove(index + 1);
                 } else {
-                    index = index - 1;
+                    index -= 1;
                 }
             }
         }
     }
 
-    fn checksum(&self) -> u64 {
+    pub fn checksum(&self) -> u64 {
         self.blocks.iter().map(|b| b.checksum()).sum()
     }
 }
@@ -136,15 +142,25 @@ impl fmt::Display for Disk {
     }
 }
 
-fn parse_input(input: &str) -> Disk {
+pub fn parse_input(input: &str) -> Result<Disk, SolveError> {
     let trimmed = input.trim();
     let mut index: u32 = 0;
     let mut file_id = 0;
     let mut is_empty = false;
     let mut blocks: Vec<Block> = Vec::new();
 
-    for char in trimmed.chars() {
-        let size = char.to_digit(10).unwrap() as usize;
+    if trimmed.is_empty() {
+        return Err(SolveError::unexpected_end(input, "the disk map is empty"));
+    }
+
+    for (offset, char) in trimmed.char_indices() {
+        let size = char.to_digit(10).ok_or_else(|| {
+            SolveError::invalid_input(
+                input,
+                &trimmed[offset..],
+                format!("expected a digit, found '{}'", char),
+            )
+        })? as usize;
         if is_empty {
             index += size as u32;
         } else {
@@ -163,21 +179,25 @@ fn parse_input(input: &str) -> Disk {
         is_empty = !is_empty;
     }
 
-    Disk { blocks }
+    Ok(Disk { blocks })
 }
 
 impl Solution for Day9 {
-    fn solve_part1(&self, input: &str) -> String {
-        let mut disk = parse_input(input);
+    fn parse(&self, input: &str) -> Result<(), SolveError> {
+        parse_input(input).map(|_| ())
+    }
+
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        let mut disk = parse_input(input)?;
         disk.compact(true);
 
-        disk.checksum().to_string()
+        Ok(disk.checksum().into())
     }
 
-    fn solve_part2(&self, input: &str) -> String {
-        let mut disk = parse_input(input);
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        let mut disk = parse_input(input)?;
         disk.compact(false);
 
-        disk.checksum().to_string()
+        Ok(disk.checksum().into())
     }
 }
@@ -0,0 +1,56 @@
+// Compacts the disk one block at a time, as the puzzle describes it
+
+fn expand(digits: &[u8]) -> Vec<Option<u64>> {
+    digits
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &size)| {
+            let block = (i % 2 == 0).then_some(i as u64 / 2);
+            vec![block; size as usize]
+        })
+        .collect()
+}
+
+fn checksum(blocks: &[Option<u64>]) -> u64 {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, block)| i as u64 * block.unwrap_or(0))
+        .sum()
+}
+
+pub fn compact_blocks(digits: &[u8]) -> u64 {
+    let mut blocks = expand(digits);
+
+    while let Some(free) = blocks.iter().position(Option::is_none) {
+        match blocks.pop() {
+            Some(Some(file)) => blocks[free] = Some(file),
+            _ => continue,
+        }
+    }
+
+    checksum(&blocks)
+}
+
+pub fn compact_files(digits: &[u8]) -> u64 {
+    let mut blocks = expand(digits);
+    let files = (digits.len() as u64).div_ceil(2);
+
+    for file in (0..files).rev() {
+        let Some(start) = blocks.iter().position(|&block| block == Some(file)) else {
+            continue;
+        };
+        let size = blocks[start..]
+            .iter()
+            .take_while(|&&block| block == Some(file))
+            .count();
+
+        let free = (0..start).find(|&i| blocks[i..i + size].iter().all(Option::is_none));
+        if let Some(free) = free {
+            blocks[free..free + size].fill(Some(file));
+            blocks[start..start + size].fill(None);
+        }
+    }
+
+    checksum(&blocks)
+}
@@ -1,14 +1,43 @@
-use crate::day9::Day9;
-use crate::solution::Solution;
+use crate::day9::{oracle, parse_input};
+use crate::property::{assert_property, expect_eq};
+use crate::utils::random::Rng;
+
+fn disk_map(rng: &mut Rng) -> Vec<u8> {
+    (0..rng.range(1..=15))
+        .map(|i| match i % 2 {
+            0 => rng.range(1..=9),
+            _ => rng.range(0..=9),
+        })
+        .collect()
+}
+
+fn compacted(digits: &[u8], allow_fragmentation: bool) -> Result<u64, String> {
+    let input: String = digits
+        .iter()
+        .map(|&digit| char::from(b'0' + digit))
+        .collect();
+    let mut disk = parse_input(&input).map_err(|e| e.to_string())?;
+    disk.compact(allow_fragmentation);
+    Ok(disk.checksum())
+}
+
+// Shrinking can produce empty files and disks, which the puzzle never has
+fn is_valid(digits: &[u8]) -> bool {
+    !digits.is_empty() && digits.iter().step_by(2).all(|&digit| digit > 0)
+}
 
 #[test]
-fn test_part1() {
-    let day = Day9 {};
-    assert_eq!(day.solve_part1("2333133121414131402"), "1928");
+fn test_compact_blocks_matches_oracle() {
+    assert_property(disk_map, |digits| match is_valid(digits) {
+        true => expect_eq(compacted(digits, true)?, oracle::compact_blocks(digits)),
+        false => Ok(()),
+    });
 }
 
 #[test]
-fn test_part2() {
-    let day = Day9 {};
-    assert_eq!(day.solve_part2("2333133121414131402"), "2858");
+fn test_compact_files_matches_oracle() {
+    assert_property(disk_map, |digits| match is_valid(digits) {
+        true => expect_eq(compacted(digits, false)?, oracle::compact_files(digits)),
+        false => Ok(()),
+    });
 }
@@ -0,0 +1,135 @@
+use crate::solution::Part;
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SolveError {
+    InvalidInput {
+        day: Option<u8>,
+        part: Option<Part>,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    NoSolution {
+        day: Option<u8>,
+        part: Option<Part>,
+        message: String,
+    },
+}
+
+// Finds the 1-based line and column at which `fragment` starts, given that it is a slice of `input`
+fn locate(input: &str, fragment: &str) -> (usize, usize) {
+    let offset = (fragment.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset <= input.len())
+        .unwrap_or(0);
+    let preceding = input.get(..offset).unwrap_or_default();
+
+    let line = preceding.matches('\n').count() + 1;
+    let column = preceding.chars().rev().take_while(|&c| c != '\n').count() + 1;
+
+    (line, column)
+}
+
+impl SolveError {
+    pub fn invalid_input(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let (line, column) = locate(input, fragment);
+
+        SolveError::InvalidInput {
+            day: None,
+            part: None,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn unexpected_end(input: &str, message: impl Into<String>) -> Self {
+        Self::invalid_input(input, &input[input.len()..], message)
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        SolveError::NoSolution {
+            day: None,
+            part: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        match self {
+            SolveError::InvalidInput { day, .. } | SolveError::NoSolution { day, .. } => *day,
+        }
+    }
+
+    pub fn part(&self) -> Option<Part> {
+        match self {
+            SolveError::InvalidInput { part, .. } | SolveError::NoSolution { part, .. } => *part,
+        }
+    }
+
+    pub fn with_day(mut self, new_day: u8) -> Self {
+        match &mut self {
+            SolveError::InvalidInput { day, .. } | SolveError::NoSolution { day, .. } => {
+                *day = Some(new_day)
+            }
+        }
+        self
+    }
+
+    pub fn with_part(mut self, new_part: Part) -> Self {
+        match &mut self {
+            SolveError::InvalidInput { part, .. } | SolveError::NoSolution { part, .. } => {
+                *part = Some(new_part)
+            }
+        }
+        self
+    }
+
+    // Moves an error found in a single line of a streamed input to line `number` of the input
+    pub fn on_line(mut self, number: usize) -> Self {
+        if let SolveError::InvalidInput { line, .. } = &mut self {
+            *line += number - 1;
+        }
+        self
+    }
+
+    // Translates a position relative to `section` into a position relative to `input`
+    pub fn within(mut self, input: &str, section: &str) -> Self {
+        if let SolveError::InvalidInput { line, column, .. } = &mut self {
+            let (section_line, section_column) = locate(input, section);
+            if *line == 1 {
+                *column += section_column - 1;
+            }
+            *line += section_line - 1;
+        }
+        self
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match (self.day(), self.part()) {
+            (Some(day), Some(part)) => write!(fmt, "day {} part {}: ", day, part)?,
+            (Some(day), None) => write!(fmt, "day {}: ", day)?,
+            (None, Some(part)) => write!(fmt, "part {}: ", part)?,
+            (None, None) => {}
+        }
+
+        match self {
+            SolveError::InvalidInput {
+                line,
+                column,
+                message,
+                ..
+            } => write!(
+                fmt,
+                "invalid input at line {}, column {}: {}",
+                line, column, message
+            ),
+            SolveError::NoSolution { message, .. } => write!(fmt, "no solution: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
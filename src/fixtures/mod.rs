@@ -0,0 +1,123 @@
+use crate::inputs::{read_file, InputResolver};
+use crate::registry;
+use crate::solution::Part;
+use crate::verify::{self, Outcome};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+#[cfg(test)]
+mod test;
+
+pub const EXAMPLES_DIR: &str = "examples";
+// Limits the example tests to a single day, as the watcher does for the day it watches
+pub const EXAMPLE_DAY_VAR: &str = "AOC_EXAMPLE_DAY";
+
+// An example input with its expected answers, stored as `data/dayN/examples/<name>.txt`:
+//
+//     part1: 22
+//     part2: 6,1
+//     bytes: 12
+//     ---
+//     <input>
+//
+// Headers other than `part1` and `part2` are parameters of the day, lines starting with `#` are
+// comments. Parts without an expected answer are not checked.
+#[derive(Debug, Default, PartialEq)]
+pub struct Fixture {
+    pub name: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub params: BTreeMap<String, String>,
+    pub input: String,
+}
+
+impl Fixture {
+    pub fn parse(name: &str, content: &str) -> Result<Self, String> {
+        let mut fixture = Fixture {
+            name: name.to_string(),
+            ..Default::default()
+        };
+
+        let mut lines = content.lines().enumerate();
+        let mut separated = false;
+
+        for (index, line) in lines.by_ref() {
+            let line = line.trim();
+            if line == "---" {
+                separated = true;
+                break;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("line {}: expected 'key: value'", index + 1))?;
+            let (key, value) = (key.trim().to_string(), value.trim().to_string());
+
+            let previous = match key.as_str() {
+                "part1" => fixture.part1.replace(value),
+                "part2" => fixture.part2.replace(value),
+                _ => fixture.params.insert(key.clone(), value),
+            };
+            if previous.is_some() {
+                return Err(format!("line {}: '{}' is given twice", index + 1, key));
+            }
+        }
+
+        if !separated {
+            return Err("missing the '---' line between the headers and the input".into());
+        }
+
+        if fixture.part1.is_none() && fixture.part2.is_none() {
+            return Err("expected a 'part1' or 'part2' header".into());
+        }
+
+        fixture.input = lines.map(|(_, line)| format!("{}\n", line)).collect();
+        Ok(fixture)
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+// Fixtures of both `dayNN/examples` and `dayN/examples`, sorted by name
+pub fn load_examples(resolver: &InputResolver, day: u8) -> Result<Vec<Fixture>, String> {
+    let mut paths: Vec<PathBuf> = resolver
+        .day_dirs(day)
+        .into_iter()
+        .filter_map(|dir| fs::read_dir(dir.join(EXAMPLES_DIR)).ok())
+        .flat_map(|entries| entries.flatten().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect();
+    paths.sort_by_key(|path| path.file_name().map(|name| name.to_owned()));
+
+    paths
+        .iter()
+        .map(|path| {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            Fixture::parse(&name, &read_file(path)?)
+                .map_err(|e| format!("Invalid example '{}': {}", path.display(), e))
+        })
+        .collect()
+}
+
+pub fn check_fixture(day: u8, fixture: &Fixture) -> Result<Vec<(Part, Outcome)>, String> {
+    let solution = registry::configure(day, &fixture.params)?;
+
+    Ok([Part::One, Part::Two]
+        .into_iter()
+        .filter_map(|part| {
+            let expected = fixture.expected(part)?;
+            let outcome =
+                verify::check_part(Some(expected), solution.as_ref(), part, &fixture.input);
+            Some((part, outcome))
+        })
+        .collect())
+}
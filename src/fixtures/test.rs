@@ -0,0 +1,85 @@
+use crate::fixtures::{check_fixture, load_examples, Fixture, EXAMPLE_DAY_VAR};
+use crate::inputs::InputResolver;
+use crate::registry::DAYS;
+use crate::solution::Part;
+use crate::verify::Outcome;
+use std::env;
+use std::path::Path;
+
+#[test]
+fn test_parse() {
+    let fixture = Fixture::parse(
+        "small",
+        "# Only the first twelve bytes\npart1: 22\nbytes: 12\n---\n5,4\n4,2\n",
+    )
+    .unwrap();
+
+    assert_eq!(fixture.name, "small");
+    assert_eq!(fixture.expected(Part::One), Some("22"));
+    assert_eq!(fixture.expected(Part::Two), None);
+    assert_eq!(fixture.params.get("bytes").map(String::as_str), Some("12"));
+    assert_eq!(fixture.input, "5,4\n4,2\n");
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(Fixture::parse("a", "part1: 1\n3   4\n").is_err());
+    assert!(Fixture::parse("a", "part1 1\n---\n3   4\n").is_err());
+    assert!(Fixture::parse("a", "part1: 1\npart1: 2\n---\n3   4\n").is_err());
+    assert!(Fixture::parse("a", "bytes: 12\n---\n3   4\n").is_err());
+}
+
+#[test]
+fn test_check_fixture() {
+    let fixture = Fixture::parse(
+        "sample",
+        "part1: 11\npart2: 30\n---\n3 4\n4 3\n2 5\n1 3\n3 9\n3 3\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        check_fixture(1, &fixture).unwrap(),
+        vec![
+            (Part::One, Outcome::Pass),
+            (Part::Two, Outcome::Fail("expected '30', got '31'".into()))
+        ]
+    );
+
+    let unknown = Fixture::parse("sample", "part1: 11\nrobots: 3\n---\n3 4\n").unwrap();
+    assert!(check_fixture(1, &unknown).is_err());
+}
+
+// Runs every example in `data/dayN/examples`
+#[test]
+fn test_examples() {
+    let resolver = InputResolver::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("data"));
+    let mut failures = vec![];
+
+    let days = match env::var(EXAMPLE_DAY_VAR).ok().filter(|day| !day.is_empty()) {
+        Some(day) => vec![day.parse().expect("expected the number of a day")],
+        None => DAYS.collect(),
+    };
+
+    for day in days {
+        for fixture in load_examples(&resolver, day).unwrap() {
+            let outcomes = match check_fixture(day, &fixture) {
+                Ok(outcomes) => outcomes,
+                Err(e) => {
+                    failures.push(format!("day {} {}: {}", day, fixture.name, e));
+                    continue;
+                }
+            };
+
+            for (part, outcome) in outcomes {
+                if outcome != Outcome::Pass {
+                    failures.push(format!(
+                        "day {} {} part {}: {}",
+                        day, fixture.name, part, outcome
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,121 @@
+use crate::utils::random::Rng;
+use crate::{
+    day1, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day2, day20, day21,
+    day22, day23, day24, day25, day3, day4, day5, day6, day7, day8, day9,
+};
+
+#[cfg(test)]
+mod test;
+
+// Creates a valid puzzle input, `size` scales it: the number of lines or items, or the side of a grid
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+#[derive(Debug, PartialEq)]
+pub struct GenerateArgs {
+    pub day: u8,
+    pub size: usize,
+    pub seed: u64,
+    // Only day 24, the number of swapped gate outputs
+    pub swaps: Option<usize>,
+}
+
+impl GenerateArgs {
+    pub fn new(day: u8) -> Self {
+        Self {
+            day,
+            size: 50,
+            seed: 1,
+            swaps: None,
+        }
+    }
+}
+
+pub fn generator(day: u8) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => day1::generate::generate,
+        2 => day2::generate::generate,
+        3 => day3::generate::generate,
+        4 => day4::generate::generate,
+        5 => day5::generate::generate,
+        6 => day6::generate::generate,
+        7 => day7::generate::generate,
+        8 => day8::generate::generate,
+        9 => day9::generate::generate,
+        10 => day10::generate::generate,
+        11 => day11::generate::generate,
+        12 => day12::generate::generate,
+        13 => day13::generate::generate,
+        14 => day14::generate::generate,
+        15 => day15::generate::generate,
+        16 => day16::generate::generate,
+        17 => day17::generate::generate,
+        18 => day18::generate::generate,
+        19 => day19::generate::generate,
+        20 => day20::generate::generate,
+        21 => day21::generate::generate,
+        22 => day22::generate::generate,
+        23 => day23::generate::generate,
+        24 => day24::generate::generate,
+        25 => day25::generate::generate,
+        _ => return None,
+    };
+
+    Some(generator)
+}
+
+pub fn generate(args: &GenerateArgs) -> Result<String, String> {
+    let mut rng = Rng::new(args.seed);
+
+    match (args.day, args.swaps) {
+        (24, Some(swaps)) => day24::generate::adder(&mut rng, args.size, swaps),
+        (_, Some(_)) => Err("'--swaps' only applies to day 24".into()),
+        (day, None) => {
+            let generator = generator(day).ok_or(format!("Day {} has no generator", day))?;
+            Ok(generator(&mut rng, args.size))
+        }
+    }
+}
+
+pub fn render_grid(grid: &[Vec<char>]) -> String {
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+// The smallest odd side of at least `size`, so a maze has walls on all borders
+pub fn odd_side(size: usize) -> usize {
+    size.max(5) | 1
+}
+
+// A perfect maze, every floor tile is reachable through exactly one path. Cells are the tiles at
+// odd coordinates, the tiles between two cells are carved out when the cells are connected.
+pub fn maze(rng: &mut Rng, side: usize) -> Vec<Vec<char>> {
+    let mut grid = vec![vec!['#'; side]; side];
+    let mut stack = vec![(1usize, 1usize)];
+    grid[1][1] = '.';
+
+    while let Some(&(row, col)) = stack.last() {
+        let mut neighbors: Vec<(usize, usize)> = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .iter()
+            .filter_map(|&(dr, dc): &(isize, isize)| {
+                let r = row.checked_add_signed(dr)?;
+                let c = col.checked_add_signed(dc)?;
+                (r > 0 && c > 0 && r < side - 1 && c < side - 1 && grid[r][c] == '#')
+                    .then_some((r, c))
+            })
+            .collect();
+
+        if neighbors.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        rng.shuffle(&mut neighbors);
+        let (r, c) = neighbors[0];
+        grid[(row + r) / 2][(col + c) / 2] = '.';
+        grid[r][c] = '.';
+        stack.push((r, c));
+    }
+
+    grid
+}
@@ -0,0 +1,89 @@
+use crate::day24;
+use crate::generate::{generate, generator, GenerateArgs};
+use crate::registry::{get_solution, DAYS};
+use crate::utils::random::Rng;
+
+#[test]
+fn test_inputs_are_valid() {
+    let mut failures = vec![];
+
+    for day in DAYS {
+        let solution = get_solution(day).unwrap();
+        let input = generate(&GenerateArgs::new(day)).unwrap();
+
+        // The z3 solver of day 24 is too slow to run on every test, so only its parsing is checked
+        let result = match day {
+            24 if cfg!(feature = "z3") => solution.parse(&input).map(|_| ()),
+            _ => solution
+                .solve_part1(&input)
+                .and_then(|_| solution.solve_part2(&input))
+                .map(|_| ()),
+        };
+        if let Err(e) = result {
+            failures.push(format!("day {}: {}", day, e));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn test_deterministic() {
+    for day in DAYS {
+        let mut args = GenerateArgs::new(day);
+        let first = generate(&args).unwrap();
+        assert_eq!(first, generate(&args).unwrap(), "day {}", day);
+
+        args.seed = 2;
+        assert_ne!(first, generate(&args).unwrap(), "day {}", day);
+    }
+}
+
+#[test]
+fn test_single_track() {
+    let input = generator(20).unwrap()(&mut Rng::new(3), 31);
+    let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+    // Every tile of the track has two neighbours on the track, except for the start and the end
+    for (row, line) in grid.iter().enumerate() {
+        for (col, &tile) in line.iter().enumerate() {
+            if tile == '#' {
+                continue;
+            }
+
+            let neighbors = [
+                (row - 1, col),
+                (row + 1, col),
+                (row, col - 1),
+                (row, col + 1),
+            ]
+            .iter()
+            .filter(|&&(r, c)| grid[r][c] != '#')
+            .count();
+            let expected = if tile == '.' { 2 } else { 1 };
+            assert_eq!(neighbors, expected, "tile ({}, {})", row, col);
+        }
+    }
+}
+
+#[test]
+fn test_swaps() {
+    let solution = get_solution(24).unwrap();
+
+    for swaps in [0, 2] {
+        let input = day24::generate::adder(&mut Rng::new(5), 8, swaps).unwrap();
+        assert!(solution.parse(&input).is_ok());
+    }
+
+    assert!(day24::generate::adder(&mut Rng::new(5), 4, 4).is_err());
+    assert!(generate(&GenerateArgs {
+        size: 1,
+        ..GenerateArgs::new(24)
+    })
+    .is_ok());
+    assert!(generate(&GenerateArgs {
+        swaps: Some(1),
+        ..GenerateArgs::new(9)
+    })
+    .is_err());
+}
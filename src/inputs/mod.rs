@@ -0,0 +1,114 @@
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod test;
+
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    // A file in the day's data directory, e.g. `input` or `alt1`
+    Named(String),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Named("input".to_string())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputResolver {
+    root: PathBuf,
+}
+
+pub fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Failed to read '{}': {}", path.display(), e))
+}
+
+impl InputResolver {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    // Uses `$AOC_DATA_DIR` as the data root, falling back to `data`
+    pub fn from_env() -> Self {
+        Self::new(env::var_os(DATA_DIR_VAR).unwrap_or_else(|| "data".into()))
+    }
+
+    // Both `day05` and `day5` are accepted, the zero-padded directory takes precedence
+    pub fn day_dirs(&self, day: u8) -> Vec<PathBuf> {
+        let mut dirs = vec![
+            self.root.join(format!("day{:02}", day)),
+            self.root.join(format!("day{}", day)),
+        ];
+        dirs.dedup();
+        dirs
+    }
+
+    pub fn candidates(&self, day: u8, file_name: &str) -> Vec<PathBuf> {
+        self.day_dirs(day)
+            .into_iter()
+            .map(|dir| dir.join(file_name))
+            .collect()
+    }
+
+    pub fn find(&self, day: u8, file_name: &str) -> Result<PathBuf, String> {
+        let candidates = self.candidates(day, file_name);
+
+        match candidates.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(format!(
+                "No '{}' found for day {}, tried:\n{}",
+                file_name,
+                day,
+                candidates
+                    .iter()
+                    .map(|path| format!("    {}", path.display()))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )),
+        }
+    }
+
+    pub fn read(&self, day: u8, source: &InputSource) -> Result<String, String> {
+        match source {
+            InputSource::Named(name) => read_file(&self.find(day, &input_file_name(name))?),
+            InputSource::Path(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("Failed to read stdin: {}", e))?;
+                Ok(input)
+            }
+        }
+    }
+
+    // Opens the input for reading line by line, without loading it at once
+    pub fn open(&self, day: u8, source: &InputSource) -> Result<Box<dyn BufRead>, String> {
+        let path = match source {
+            InputSource::Named(name) => self.find(day, &input_file_name(name))?,
+            InputSource::Path(path) => path.clone(),
+            InputSource::Stdin => return Ok(Box::new(io::stdin().lock())),
+        };
+
+        let file =
+            File::open(&path).map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+// `alt1` refers to `alt1.txt`, names with an extension are used as is
+fn input_file_name(name: &str) -> String {
+    match Path::new(name).extension() {
+        Some(_) => name.to_string(),
+        None => format!("{}.txt", name),
+    }
+}
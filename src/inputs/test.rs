@@ -0,0 +1,85 @@
+use crate::inputs::{InputResolver, InputSource};
+use std::fs;
+use std::io::BufRead;
+use std::path::PathBuf;
+
+fn temp_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-inputs-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    root
+}
+
+#[test]
+fn test_candidates() {
+    let resolver = InputResolver::new("data");
+
+    assert_eq!(
+        resolver.candidates(5, "input.txt"),
+        vec![
+            PathBuf::from("data/day05/input.txt"),
+            PathBuf::from("data/day5/input.txt")
+        ]
+    );
+    assert_eq!(
+        resolver.candidates(25, "input.txt"),
+        vec![PathBuf::from("data/day25/input.txt")]
+    );
+}
+
+#[test]
+fn test_read_named() {
+    let root = temp_root("named");
+    fs::create_dir_all(root.join("day5")).unwrap();
+    fs::write(root.join("day5/input.txt"), "unpadded").unwrap();
+    fs::write(root.join("day5/alt1.txt"), "alternative").unwrap();
+
+    let resolver = InputResolver::new(&root);
+    assert_eq!(
+        resolver.read(5, &InputSource::default()),
+        Ok("unpadded".to_string())
+    );
+    assert_eq!(
+        resolver.read(5, &InputSource::Named("alt1".into())),
+        Ok("alternative".to_string())
+    );
+
+    fs::create_dir_all(root.join("day05")).unwrap();
+    fs::write(root.join("day05/input.txt"), "padded").unwrap();
+    assert_eq!(
+        resolver.read(5, &InputSource::default()),
+        Ok("padded".to_string())
+    );
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_missing_input() {
+    let root = temp_root("missing");
+    let error = InputResolver::new(&root)
+        .read(7, &InputSource::Named("alt2".into()))
+        .unwrap_err();
+
+    assert!(error.contains("No 'alt2.txt' found for day 7"));
+    assert!(error.contains(&root.join("day07/alt2.txt").display().to_string()));
+    assert!(error.contains(&root.join("day7/alt2.txt").display().to_string()));
+}
+
+#[test]
+fn test_open() {
+    let root = temp_root("open");
+    fs::create_dir_all(root.join("day5")).unwrap();
+    fs::write(root.join("day5/input.txt"), "first\nsecond\n").unwrap();
+
+    let resolver = InputResolver::new(&root);
+    let lines: Vec<String> = resolver
+        .open(5, &InputSource::default())
+        .unwrap()
+        .lines()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(lines, vec!["first", "second"]);
+
+    assert!(resolver.open(6, &InputSource::default()).is_err());
+    fs::remove_dir_all(&root).unwrap();
+}
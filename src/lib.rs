@@ -0,0 +1,47 @@
+//! Solutions for Advent of Code 2024, together with the parsing and grid utilities they share.
+//!
+//! Every puzzle lives in its own `dayN` module, exposing a `DayN` [`solution::Solution`] and the
+//! domain types it is built on. The `advent-of-code-2024` binary is a thin command line on top.
+
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod error;
+pub mod fixtures;
+pub mod generate;
+pub mod inputs;
+pub mod logging;
+pub mod params;
+pub mod property;
+pub mod registry;
+pub mod runner;
+pub mod solution;
+pub mod utils;
+pub mod verify;
+pub mod watch;
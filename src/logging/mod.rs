@@ -0,0 +1,96 @@
+use crate::utils::render::ColorMode;
+use log::{LevelFilter, Log, Metadata, Record};
+use std::fs::File;
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogConfig {
+    pub level: LevelFilter,
+    pub days: Vec<u8>,
+    pub file: Option<PathBuf>,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            level: LevelFilter::Warn,
+            days: Vec::new(),
+            file: None,
+        }
+    }
+}
+
+struct Logger {
+    level: LevelFilter,
+    days: Vec<u8>,
+    sink: Mutex<Box<dyn Write + Send>>,
+}
+
+// Solver diagnostics are logged from `crate::dayN`, so the day can be read from the target
+fn day_of(target: &str) -> Option<u8> {
+    target
+        .split("::")
+        .find_map(|module| module.strip_prefix("day")?.parse().ok())
+}
+
+impl Logger {
+    fn accepts(&self, level: log::Level, target: &str) -> bool {
+        level <= self.level
+            && (self.days.is_empty() || day_of(target).is_none_or(|day| self.days.contains(&day)))
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.accepts(metadata.level(), metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let source = match day_of(record.target()) {
+            Some(day) => format!("day {}", day),
+            None => record.target().to_string(),
+        };
+
+        let mut sink = self.sink.lock().unwrap();
+        // Diagnostics are best effort, a failing sink must not abort a solver
+        let _ = writeln!(sink, "[{:<5} {}] {}", record.level(), source, record.args());
+    }
+
+    fn flush(&self) {
+        let _ = self.sink.lock().unwrap().flush();
+    }
+}
+
+pub fn init(config: LogConfig) -> Result<(), String> {
+    let sink: Box<dyn Write + Send> = match &config.file {
+        Some(path) => Box::new(
+            File::create(path)
+                .map_err(|e| format!("Failed to create '{}': {}", path.display(), e))?,
+        ),
+        None => Box::new(io::stderr()),
+    };
+    // Canvases in the diagnostics are only colored when they end up on a terminal
+    ColorMode::set_display(ColorMode::detect(
+        config.file.is_none() && io::stderr().is_terminal(),
+    ));
+
+    let logger = Logger {
+        level: config.level,
+        days: config.days,
+        sink: Mutex::new(sink),
+    };
+
+    log::set_boxed_logger(Box::new(logger)).map_err(|e| e.to_string())?;
+    log::set_max_level(config.level);
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use crate::logging::{day_of, Logger};
+use log::{Level, LevelFilter};
+use std::io;
+use std::sync::Mutex;
+
+fn logger(level: LevelFilter, days: Vec<u8>) -> Logger {
+    Logger {
+        level,
+        days,
+        sink: Mutex::new(Box::new(io::sink())),
+    }
+}
+
+#[test]
+fn test_day_of() {
+    assert_eq!(day_of("advent_of_code_2024::day12"), Some(12));
+    assert_eq!(day_of("advent_of_code_2024::day17::parser"), Some(17));
+    assert_eq!(day_of("advent_of_code_2024::runner"), None);
+}
+
+#[test]
+fn test_accepts() {
+    let logger = logger(LevelFilter::Info, vec![14, 24]);
+
+    assert!(logger.accepts(Level::Info, "advent_of_code_2024::day14"));
+    assert!(!logger.accepts(Level::Debug, "advent_of_code_2024::day14"));
+    assert!(!logger.accepts(Level::Info, "advent_of_code_2024::day12"));
+    assert!(logger.accepts(Level::Warn, "advent_of_code_2024::runner"));
+}
@@ -1,39 +1,25 @@
-use crate::solution::Solution;
-use code_timing_macros::time_snippet;
-use std::fs;
+use advent_of_code_2024::{cli, logging};
+use std::process::ExitCode;
 
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod solution;
-mod utils;
+fn main() -> ExitCode {
+    let command = cli::parse_log_args(std::env::args().skip(1)).and_then(|(config, args)| {
+        logging::init(config)?;
+        cli::parse_args(args)
+    });
 
-fn main() {
-    let input = fs::read_to_string(&"data/day25/input.txt").expect("Failed to read file.");
-    let day = day25::Day25 {};
-    let result = time_snippet!(day.solve(&input));
+    let command = match command {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
 
-    println!("{}", result)
+    match cli::execute(command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
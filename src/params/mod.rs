@@ -0,0 +1,213 @@
+use crate::registry;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[cfg(test)]
+mod test;
+
+// Parameter values by name, as given on the command line, in a file or in an example header
+pub type Overrides = BTreeMap<String, String>;
+
+pub trait ParamValue: Sized {
+    fn parse_value(value: &str) -> Option<Self>;
+    fn show(&self) -> String;
+}
+
+macro_rules! impl_param_value {
+    ($($t:ty),*) => {
+        $(
+            impl ParamValue for $t {
+                fn parse_value(value: &str) -> Option<Self> {
+                    value.parse().ok()
+                }
+
+                fn show(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_param_value!(u32, u64, usize, i32, i64, f32, f64);
+
+// Optional parameters are derived from the input when set to `auto`
+impl<T: ParamValue> ParamValue for Option<T> {
+    fn parse_value(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(None),
+            value => T::parse_value(value).map(Some),
+        }
+    }
+
+    fn show(&self) -> String {
+        match self {
+            Some(value) => value.show(),
+            None => "auto".to_string(),
+        }
+    }
+}
+
+pub fn parse_value<T: ParamValue>(key: &str, value: &str) -> Result<T, String> {
+    T::parse_value(value).ok_or_else(|| format!("Invalid value '{}' for '{}'", value, key))
+}
+
+// Accepts values of at least `min`
+pub fn at_least<T: ParamValue + PartialOrd>(min: T) -> impl Fn(&T) -> Result<(), String> {
+    move |value| {
+        if *value >= min {
+            Ok(())
+        } else {
+            Err(format!("expected at least {}", min.show()))
+        }
+    }
+}
+
+// Accepts `auto` and any value accepted by `check`
+pub fn auto_or<T>(
+    check: impl Fn(&T) -> Result<(), String>,
+) -> impl Fn(&Option<T>) -> Result<(), String> {
+    move |value| value.as_ref().map_or(Ok(()), &check)
+}
+
+pub fn finite(value: &f32) -> Result<(), String> {
+    if value.is_finite() {
+        Ok(())
+    } else {
+        Err("expected a finite number".to_string())
+    }
+}
+
+pub fn check_value<T: ParamValue>(
+    key: &str,
+    value: &T,
+    check: impl Fn(&T) -> Result<(), String>,
+) -> Result<(), String> {
+    check(value).map_err(|e| format!("Invalid value '{}' for '{}', {}", value.show(), key, e))
+}
+
+pub trait Parameters: Default {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+    fn values(&self) -> Vec<(&'static str, String)>;
+
+    fn from_overrides(overrides: &Overrides) -> Result<Self, String> {
+        let mut params = Self::default();
+        for (key, value) in overrides {
+            params.set(key, value)?;
+        }
+        Ok(params)
+    }
+}
+
+// Declares the puzzle constants of a day together with their defaults and an optional check of
+// the values they accept:
+//
+//     parameters! {
+//         pub struct Day11Params {
+//             part1_blinks: u32 = 25 => at_least(1),
+//         }
+//     }
+macro_rules! parameters {
+    (pub struct $name:ident { $($field:ident: $type:ty = $default:expr $(=> $check:expr)?,)* }) => {
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $name {
+            $(pub $field: $type,)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::params::Parameters for $name {
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    $(stringify!($field) => {
+                        let value: $type = $crate::params::parse_value(key, value)?;
+                        $($crate::params::check_value(key, &value, $check)?;)?
+                        self.$field = value;
+                    })*
+                    _ => {
+                        return Err(format!(
+                            "Unknown parameter '{}', expected one of: {}",
+                            key,
+                            [$(stringify!($field)),*].join(", ")
+                        ))
+                    }
+                }
+                Ok(())
+            }
+
+            fn values(&self) -> Vec<(&'static str, String)> {
+                vec![$((stringify!($field), $crate::params::ParamValue::show(&self.$field)),)*]
+            }
+        }
+    };
+}
+
+pub(crate) use parameters;
+
+// Overrides for several days, stored as `[dayN]` tables:
+//
+//     [day18]
+//     bytes = 12
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParamsConfig {
+    days: BTreeMap<u8, Overrides>,
+}
+
+impl ParamsConfig {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let tables: BTreeMap<String, BTreeMap<String, toml::Value>> =
+            toml::from_str(content).map_err(|e| e.message().to_string())?;
+        let mut config = Self::default();
+
+        for (table, values) in tables {
+            let day = table
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .filter(|day| registry::DAYS.contains(day))
+                .ok_or_else(|| format!("Invalid table '{}', expected 'dayN'", table))?;
+
+            for (key, value) in values {
+                let value = match value {
+                    toml::Value::String(text) => text,
+                    toml::Value::Integer(_) | toml::Value::Float(_) => value.to_string(),
+                    _ => return Err(format!("Invalid value for '{}.{}'", table, key)),
+                };
+                config.set(day, &key, &value);
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        Self::parse(&content).map_err(|e| format!("Failed to parse '{}': {}", path.display(), e))
+    }
+
+    pub fn set(&mut self, day: u8, key: &str, value: &str) {
+        self.days
+            .entry(day)
+            .or_default()
+            .insert(key.to_string(), value.to_string());
+    }
+
+    pub fn overrides(&self, day: u8) -> Overrides {
+        self.days.get(&day).cloned().unwrap_or_default()
+    }
+
+    // Checks every override against the parameters of its day
+    pub fn validate(&self) -> Result<(), String> {
+        for (&day, overrides) in &self.days {
+            registry::configure(day, overrides).map_err(|e| format!("Day {}: {}", day, e))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::day11::Day11Params;
+use crate::day14::Day14Params;
+use crate::day20::Day20Params;
+use crate::params::{Overrides, Parameters, ParamsConfig};
+
+fn overrides(pairs: &[(&str, &str)]) -> Overrides {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_from_overrides() {
+    let params = Day20Params::from_overrides(&overrides(&[("min_saving", "64")])).unwrap();
+
+    assert_eq!(
+        params,
+        Day20Params {
+            min_saving: 64,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        params.values(),
+        vec![
+            ("part1_cheat", "2".to_string()),
+            ("part2_cheat", "20".to_string()),
+            ("min_saving", "64".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_optional_values() {
+    let params = Day14Params::from_overrides(&overrides(&[("width", "11"), ("height", "auto")]));
+
+    assert_eq!(params.as_ref().map(|p| p.width), Ok(Some(11)));
+    assert_eq!(params.as_ref().map(|p| p.height), Ok(None));
+}
+
+#[test]
+fn test_invalid_overrides() {
+    assert_eq!(
+        Day20Params::from_overrides(&overrides(&[("min_saving", "lots")])),
+        Err("Invalid value 'lots' for 'min_saving'".to_string())
+    );
+    assert_eq!(
+        Day20Params::from_overrides(&overrides(&[("saving", "64")])),
+        Err(
+            "Unknown parameter 'saving', expected one of: part1_cheat, part2_cheat, min_saving"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn test_out_of_range_overrides() {
+    assert_eq!(
+        Day11Params::from_overrides(&overrides(&[("part1_blinks", "0")])),
+        Err("Invalid value '0' for 'part1_blinks', expected at least 1".to_string())
+    );
+    assert_eq!(
+        Day14Params::from_overrides(&overrides(&[("width", "-3")])),
+        Err("Invalid value '-3' for 'width', expected at least 1".to_string())
+    );
+    assert_eq!(
+        Day14Params::from_overrides(&overrides(&[("tree_threshold", "NaN")])),
+        Err("Invalid value 'NaN' for 'tree_threshold', expected a finite number".to_string())
+    );
+
+    let config = ParamsConfig::parse("[day22]\niterations = 3").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_config() {
+    let config = ParamsConfig::parse(
+        r#"
+        [day14]
+        width = 11
+        tree_threshold = 0.5
+
+        [day18]
+        bytes = "12"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.overrides(14),
+        overrides(&[("tree_threshold", "0.5"), ("width", "11")])
+    );
+    assert_eq!(config.overrides(18), overrides(&[("bytes", "12")]));
+    assert_eq!(config.overrides(20), Overrides::new());
+    assert_eq!(config.validate(), Ok(()));
+}
+
+#[test]
+fn test_invalid_config() {
+    assert!(ParamsConfig::parse("[day26]\nbytes = 12").is_err());
+    assert!(ParamsConfig::parse("[robots]\nbytes = 12").is_err());
+    assert!(ParamsConfig::parse("[day18]\nbytes = [12]").is_err());
+
+    let config = ParamsConfig::parse("[day1]\nbytes = 12").unwrap();
+    assert!(config.validate().is_err());
+}
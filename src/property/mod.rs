@@ -0,0 +1,198 @@
+use crate::utils::random::Rng;
+use std::fmt;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+#[cfg(test)]
+mod test;
+
+pub const CASES: usize = 200;
+
+// Smaller versions of a value, tried in order when a property fails for it
+pub trait Shrink: Clone + Debug {
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_shrink_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Shrink for $t {
+                fn shrink(&self) -> Vec<Self> {
+                    let value = *self;
+                    let towards_zero = if value > 0 { value - 1 } else { value + 1 };
+                    let mut candidates = vec![];
+
+                    for candidate in [0, value / 2, towards_zero] {
+                        if value != 0 && candidate != value && !candidates.contains(&candidate) {
+                            candidates.push(candidate);
+                        }
+                    }
+                    candidates
+                }
+            }
+        )*
+    };
+}
+
+impl_shrink_integer!(u8, u32, u64, usize, i32, i64);
+
+impl Shrink for char {
+    fn shrink(&self) -> Vec<Self> {
+        match *self {
+            'A' => vec![],
+            _ => vec!['A'],
+        }
+    }
+}
+
+// Drops halves and single elements before shrinking the elements themselves
+impl<T: Shrink> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = vec![];
+        let half = self.len() / 2;
+
+        if half > 0 {
+            candidates.push(self[..half].to_vec());
+            candidates.push(self[half..].to_vec());
+        }
+
+        for i in 0..self.len() {
+            let mut removed = self.clone();
+            removed.remove(i);
+            candidates.push(removed);
+        }
+
+        for (i, item) in self.iter().enumerate() {
+            for smaller in item.shrink() {
+                let mut replaced = self.clone();
+                replaced[i] = smaller;
+                candidates.push(replaced);
+            }
+        }
+
+        candidates
+    }
+}
+
+impl<A: Shrink, B: Shrink> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b) = self;
+        let firsts = a.shrink().into_iter().map(|a| (a, b.clone()));
+        let seconds = b.shrink().into_iter().map(|b| (a.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+impl<A: Shrink, B: Shrink, C: Shrink> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b, c) = self;
+        ((a.clone(), b.clone()), c.clone())
+            .shrink()
+            .into_iter()
+            .map(|((a, b), c)| (a, b, c))
+            .collect()
+    }
+}
+
+// A value for which a property does not hold, together with the smallest failing value found by
+// shrinking it
+#[derive(Debug)]
+pub struct Counterexample<T> {
+    pub seed: u64,
+    pub original: T,
+    pub minimal: T,
+    pub shrinks: usize,
+    pub message: String,
+}
+
+impl<T: Debug> fmt::Display for Counterexample<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            fmt,
+            "property failed for seed {}: {}",
+            self.seed, self.message
+        )?;
+        writeln!(
+            fmt,
+            "minimal input ({} shrinks): {:?}",
+            self.shrinks, self.minimal
+        )?;
+        write!(fmt, "original input: {:?}", self.original)
+    }
+}
+
+// Compares a solver with its reference, as the result of a property
+pub fn expect_eq<T: PartialEq + Debug>(actual: T, expected: T) -> Result<(), String> {
+    match actual == expected {
+        true => Ok(()),
+        false => Err(format!("expected {:?}, got {:?}", expected, actual)),
+    }
+}
+
+// Panics of the code under test count as failures, so they are shrunk as well
+fn holds<T>(property: &impl Fn(&T) -> Result<(), String>, value: &T) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| property(value))).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(format!("panicked: {}", message))
+    })
+}
+
+fn shrink<T: Shrink>(
+    property: &impl Fn(&T) -> Result<(), String>,
+    mut value: T,
+    mut message: String,
+) -> (T, usize, String) {
+    let mut shrinks = 0;
+
+    'smaller: loop {
+        for candidate in value.shrink() {
+            if let Err(candidate_message) = holds(property, &candidate) {
+                value = candidate;
+                message = candidate_message;
+                shrinks += 1;
+                continue 'smaller;
+            }
+        }
+
+        return (value, shrinks, message);
+    }
+}
+
+// Runs a property on `cases` generated values, case `i` uses the generator seeded with `i`.
+// Properties should accept any value the shrinking produces, returning `Ok` for values outside of
+// what they check.
+pub fn check<T: Shrink>(
+    cases: usize,
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Result<(), Counterexample<T>> {
+    for seed in 0..cases as u64 {
+        let original = generate(&mut Rng::new(seed));
+
+        if let Err(message) = holds(&property, &original) {
+            let (minimal, shrinks, message) = shrink(&property, original.clone(), message);
+            return Err(Counterexample {
+                seed,
+                original,
+                minimal,
+                shrinks,
+                message,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn assert_property<T: Shrink>(
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    if let Err(counterexample) = check(CASES, generate, property) {
+        panic!("{}", counterexample);
+    }
+}
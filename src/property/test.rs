@@ -0,0 +1,69 @@
+use crate::property::{check, expect_eq, Shrink};
+
+#[test]
+fn test_shrink_integers() {
+    assert_eq!(10u32.shrink(), vec![0, 5, 9]);
+    assert_eq!((-3i64).shrink(), vec![0, -1, -2]);
+    assert_eq!(1usize.shrink(), vec![0]);
+    assert!(0i32.shrink().is_empty());
+}
+
+#[test]
+fn test_shrink_vec() {
+    assert_eq!(
+        vec![2u8, 1].shrink(),
+        vec![
+            vec![2],
+            vec![1],
+            vec![1],
+            vec![2],
+            vec![0, 1],
+            vec![1, 1],
+            vec![2, 0]
+        ]
+    );
+    assert!(Vec::<u8>::new().shrink().is_empty());
+}
+
+#[test]
+fn test_check_passes() {
+    assert!(check(
+        50,
+        |rng| rng.range(0..=10u32),
+        |&value| expect_eq(value <= 10, true)
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_check_shrinks() {
+    let counterexample = check(
+        50,
+        |rng| (0..8).map(|_| rng.range(0..=20u32)).collect::<Vec<_>>(),
+        |values| match values.iter().all(|&value| value < 10) {
+            true => Ok(()),
+            false => Err("value too large".into()),
+        },
+    )
+    .unwrap_err();
+
+    assert_eq!(counterexample.seed, 0);
+    assert_eq!(counterexample.minimal, vec![10]);
+    assert_eq!(counterexample.message, "value too large");
+}
+
+#[test]
+fn test_check_catches_panics() {
+    let counterexample = check(
+        50,
+        |rng| rng.range(0..=100usize),
+        |&value| {
+            assert!(value < 30, "too large");
+            Ok(())
+        },
+    )
+    .unwrap_err();
+
+    assert_eq!(counterexample.minimal, 30);
+    assert!(counterexample.message.contains("too large"));
+}
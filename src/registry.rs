@@ -0,0 +1,100 @@
+use crate::day1::Day1;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::day12::Day12;
+use crate::day13::Day13;
+use crate::day14::Day14;
+use crate::day15::Day15;
+use crate::day16::Day16;
+use crate::day17::Day17;
+use crate::day18::Day18;
+use crate::day19::Day19;
+use crate::day2::Day2;
+use crate::day20::Day20;
+use crate::day21::Day21;
+use crate::day22::Day22;
+use crate::day23::Day23;
+use crate::day24::Day24;
+use crate::day25::Day25;
+use crate::day3::Day3;
+use crate::day4::Day4;
+use crate::day5::Day5;
+use crate::day6::Day6;
+use crate::day7::Day7;
+use crate::day8::Day8;
+use crate::day9::Day9;
+use crate::params::{Overrides, Parameters};
+use crate::solution::Solution;
+use std::ops::RangeInclusive;
+
+pub const DAYS: RangeInclusive<u8> = 1..=25;
+
+fn fixed(
+    solution: impl Solution + 'static,
+    overrides: &Overrides,
+) -> Result<Box<dyn Solution>, String> {
+    match overrides.keys().next() {
+        Some(key) => Err(format!(
+            "Unknown parameter '{}', the day has no parameters",
+            key
+        )),
+        None => Ok(Box::new(solution)),
+    }
+}
+
+// Creates a solution with some of its puzzle constants replaced, samples often use smaller ones
+pub fn configure(day: u8, overrides: &Overrides) -> Result<Box<dyn Solution>, String> {
+    let solution: Box<dyn Solution> = match day {
+        1 => fixed(Day1 {}, overrides)?,
+        2 => fixed(Day2 {}, overrides)?,
+        3 => fixed(Day3 {}, overrides)?,
+        4 => fixed(Day4 {}, overrides)?,
+        5 => fixed(Day5 {}, overrides)?,
+        6 => fixed(Day6 {}, overrides)?,
+        7 => fixed(Day7 {}, overrides)?,
+        8 => fixed(Day8 {}, overrides)?,
+        9 => fixed(Day9 {}, overrides)?,
+        10 => fixed(Day10 {}, overrides)?,
+        11 => Box::new(Day11 {
+            params: Parameters::from_overrides(overrides)?,
+        }),
+        12 => fixed(Day12 {}, overrides)?,
+        13 => Box::new(Day13 {
+            params: Parameters::from_overrides(overrides)?,
+        }),
+        14 => Box::new(Day14 {
+            params: Parameters::from_overrides(overrides)?,
+        }),
+        15 => fixed(Day15 {}, overrides)?,
+        16 => fixed(Day16 {}, overrides)?,
+        17 => fixed(Day17 {}, overrides)?,
+        18 => Box::new(Day18 {
+            params: Parameters::from_overrides(overrides)?,
+        }),
+        19 => fixed(Day19 {}, overrides)?,
+        20 => Box::new(Day20 {
+            params: Parameters::from_overrides(overrides)?,
+        }),
+        21 => Box::new(Day21 {
+            params: Parameters::from_overrides(overrides)?,
+        }),
+        22 => Box::new(Day22 {
+            params: Parameters::from_overrides(overrides)?,
+        }),
+        23 => fixed(Day23 {}, overrides)?,
+        24 => fixed(Day24 {}, overrides)?,
+        25 => fixed(Day25 {}, overrides)?,
+        _ => return Err(format!("Day {} is not registered", day)),
+    };
+
+    Ok(solution)
+}
+
+pub fn get_solution(day: u8) -> Option<Box<dyn Solution>> {
+    configure(day, &Overrides::new()).ok()
+}
+
+pub fn all_solutions() -> Vec<(u8, Box<dyn Solution>)> {
+    DAYS.filter_map(|day| get_solution(day).map(|s| (day, s)))
+        .collect()
+}
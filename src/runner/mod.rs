@@ -0,0 +1,226 @@
+use crate::inputs::{InputResolver, InputSource};
+use crate::params::ParamsConfig;
+use crate::registry;
+use crate::solution::{Answer, Part, Solution};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Failed,
+    Panicked,
+    Skipped,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Solved => fmt.pad("solved"),
+            Status::Failed => fmt.pad("failed"),
+            Status::Panicked => fmt.pad("panicked"),
+            Status::Skipped => fmt.pad("skipped"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartReport {
+    pub part: Part,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub message: Option<String>,
+    #[serde(rename = "time_ms", with = "millis")]
+    pub time: Duration,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub parts: Vec<PartReport>,
+    #[serde(rename = "time_ms", with = "millis")]
+    pub time: Duration,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub parallel: bool,
+    pub days: Vec<DayReport>,
+    #[serde(rename = "time_ms", with = "millis")]
+    pub time: Duration,
+}
+
+mod millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        f64::deserialize(deserializer).map(|millis| Duration::from_secs_f64(millis / 1000.0))
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn run_part(solution: &dyn Solution, part: Part, input: &str) -> PartReport {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solution.solve_part(part, input)));
+    let time = start.elapsed();
+
+    let (status, answer, message) = match result {
+        Ok(Ok(answer)) => (Status::Solved, Some(answer), None),
+        Ok(Err(e)) => (Status::Failed, None, Some(e.to_string())),
+        Err(payload) => (Status::Panicked, None, Some(panic_message(payload))),
+    };
+
+    PartReport {
+        part,
+        status,
+        answer,
+        message,
+        time,
+    }
+}
+
+pub fn run_day(
+    solution: &dyn Solution,
+    day: u8,
+    input: Result<String, String>,
+    parts: &[Part],
+) -> DayReport {
+    let start = Instant::now();
+    let parts = parts
+        .iter()
+        .map(|&part| match &input {
+            Ok(input) => run_part(solution, part, input),
+            Err(e) => PartReport {
+                part,
+                status: Status::Skipped,
+                answer: None,
+                message: Some(e.clone()),
+                time: Duration::ZERO,
+            },
+        })
+        .collect();
+
+    DayReport {
+        day,
+        parts,
+        time: start.elapsed(),
+    }
+}
+
+pub fn run_one(
+    solution: &dyn Solution,
+    day: u8,
+    input: Result<String, String>,
+    parts: &[Part],
+) -> Report {
+    let start = Instant::now();
+    let day = run_day(solution, day, input, parts);
+
+    Report {
+        parallel: false,
+        days: vec![day],
+        time: start.elapsed(),
+    }
+}
+
+// Days with invalid parameters are left out, `ParamsConfig::validate` reports them up front
+pub fn run_all(
+    resolver: &InputResolver,
+    source: &InputSource,
+    parts: &[Part],
+    params: &ParamsConfig,
+    parallel: bool,
+) -> Report {
+    // Solutions are created on the worker thread, so they do not need to be `Send`
+    let run = |day: u8| {
+        let solution = registry::configure(day, &params.overrides(day)).ok()?;
+        let input = resolver.read(day, source);
+        Some(run_day(solution.as_ref(), day, input, parts))
+    };
+
+    let start = Instant::now();
+    let days = if parallel {
+        registry::DAYS.into_par_iter().filter_map(run).collect()
+    } else {
+        registry::DAYS.filter_map(run).collect()
+    };
+
+    Report {
+        parallel,
+        days,
+        time: start.elapsed(),
+    }
+}
+
+impl Report {
+    pub fn failures(&self) -> usize {
+        self.days
+            .iter()
+            .flat_map(|day| &day.parts)
+            .filter(|part| matches!(part.status, Status::Failed | Status::Panicked))
+            .count()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("reports only contain serializable values")
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            fmt,
+            "{:>3}  {:>4}  {:<8}  {:>10}  result",
+            "day", "part", "status", "time"
+        )?;
+
+        for day in &self.days {
+            for part in &day.parts {
+                let result = match (&part.answer, &part.message) {
+                    (Some(answer), _) => answer.pretty(),
+                    (None, Some(message)) => message.clone(),
+                    (None, None) => String::new(),
+                };
+                writeln!(
+                    fmt,
+                    "{:>3}  {:>4}  {:<8}  {:>10.1?}  {}",
+                    day.day,
+                    part.part,
+                    part.status,
+                    part.time,
+                    result.lines().next().unwrap_or_default()
+                )?;
+            }
+        }
+
+        write!(
+            fmt,
+            "\n{} days in {:.1?}{}",
+            self.days.len(),
+            self.time,
+            if self.parallel { " (parallel)" } else { "" }
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use crate::error::SolveError;
+use crate::runner::{run_day, Status};
+use crate::solution::{Answer, Part, Solution};
+
+struct Flaky {}
+
+impl Solution for Flaky {
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        input
+            .trim()
+            .parse::<u32>()
+            .map(|n| (n * 2).into())
+            .map_err(|_| SolveError::invalid_input(input, input.trim(), "expected a number"))
+    }
+
+    fn solve_part2(&self, _input: &str) -> Result<Answer, SolveError> {
+        panic!("no path to the exit");
+    }
+}
+
+#[test]
+fn test_run_day() {
+    let report = run_day(&Flaky {}, 1, Ok("21".into()), &[Part::One, Part::Two]);
+
+    assert_eq!(report.parts[0].status, Status::Solved);
+    assert_eq!(report.parts[0].answer, Some(Answer::Integer(42)));
+    assert_eq!(report.parts[1].status, Status::Panicked);
+    assert_eq!(
+        report.parts[1].message.as_deref(),
+        Some("no path to the exit")
+    );
+}
+
+#[test]
+fn test_run_day_failures() {
+    let report = run_day(&Flaky {}, 1, Ok("x".into()), &[Part::One]);
+    assert_eq!(report.parts.len(), 1);
+    assert_eq!(report.parts[0].status, Status::Failed);
+
+    let report = run_day(
+        &Flaky {},
+        1,
+        Err("no input".into()),
+        &[Part::One, Part::Two],
+    );
+    assert!(report.parts.iter().all(|p| p.status == Status::Skipped));
+}
+
+#[test]
+fn test_report_json() {
+    let report = run_day(&Flaky {}, 3, Ok("1".into()), &[Part::One]);
+    let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(json["day"], 3);
+    assert_eq!(json["parts"][0]["part"], 1);
+    assert_eq!(json["parts"][0]["status"], "solved");
+    assert_eq!(json["parts"][0]["answer"]["value"], 2);
+    assert!(json["parts"][0]["time_ms"].is_f64());
+}
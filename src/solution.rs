@@ -1,9 +1,87 @@
+use crate::error::SolveError;
+use crate::utils::render::{Image, Recorder};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::BufRead;
+
+pub use crate::answer::Answer;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(part: u8) -> Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("Invalid part '{}', expected 1 or 2", part)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => fmt.pad("1"),
+            Part::Two => fmt.pad("2"),
+        }
+    }
+}
+
 pub trait Solution {
-    fn solve_part1(&self, input: &str) -> String;
-    fn solve_part2(&self, input: &str) -> String;
-    fn solve(&self, input: &str) -> String {
-        let part1 = self.solve_part1(&input);
-        let part2 = self.solve_part2(&input);
-        format!("part 1:\n{}\n\npart 2:\n{}", part1, part2)
+    // Only parses the input, so benchmarks can time parsing apart from solving
+    fn parse(&self, _input: &str) -> Result<(), SolveError> {
+        Ok(())
+    }
+    // The puzzle constants of the day with their current values
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+    // Solves a part while reading the input line by line, for inputs too large to hold in memory.
+    // Returns `None` without reading when the part has no streaming path.
+    fn solve_part_streaming(
+        &self,
+        _part: Part,
+        _reader: &mut dyn BufRead,
+    ) -> Option<Result<Answer, SolveError>> {
+        None
+    }
+    // Draws the puzzle state worth looking at, or returns `None` when the day has nothing to show
+    fn render(&self, _input: &str) -> Option<Result<Image, SolveError>> {
+        None
+    }
+    // Runs the simulation of the day, pushing its states into `recorder`. Returns `None` when the
+    // day has no simulation to record.
+    fn record(&self, _input: &str, _recorder: &mut Recorder) -> Option<Result<(), SolveError>> {
+        None
+    }
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError>;
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError>;
+    fn solve_part(&self, part: Part, input: &str) -> Result<Answer, SolveError> {
+        match part {
+            Part::One => self.solve_part1(input),
+            Part::Two => self.solve_part2(input),
+        }
+        .map_err(|e| e.with_part(part))
+    }
+    fn solve(&self, input: &str) -> Result<String, SolveError> {
+        let part1 = self.solve_part(Part::One, input)?;
+        let part2 = self.solve_part(Part::Two, input)?;
+        Ok(format!("part 1:\n{}\n\npart 2:\n{}", part1, part2))
     }
 }
@@ -1,6 +1,10 @@
 use nalgebra::{vector, Vector2};
+use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+#[cfg(test)]
+mod test;
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug, EnumIter)]
 pub enum Direction {
     Up,
@@ -10,7 +14,7 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub(crate) fn to_vector(&self) -> Vector2<i32> {
+    pub fn to_vector(&self) -> Vector2<i32> {
         match self {
             Direction::Up => vector![0, -1],
             Direction::Down => vector![0, 1],
@@ -19,7 +23,7 @@ impl Direction {
         }
     }
 
-    pub(crate) fn clockwise(&self) -> Direction {
+    pub fn clockwise(&self) -> Direction {
         match self {
             Direction::Up => Direction::Right,
             Direction::Down => Direction::Left,
@@ -28,7 +32,7 @@ impl Direction {
         }
     }
 
-    pub(crate) fn counter_clockwise(&self) -> Direction {
+    pub fn counter_clockwise(&self) -> Direction {
         match self {
             Direction::Up => Direction::Left,
             Direction::Down => Direction::Right,
@@ -37,7 +41,118 @@ impl Direction {
         }
     }
 
-    pub(crate) fn is_vertical(&self) -> bool {
+    pub fn is_vertical(&self) -> bool {
         self == &Direction::Up || self == &Direction::Down
     }
 }
+
+// The eight directions to the neighbors of a tile, in clockwise order
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, EnumIter)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    const CLOCKWISE: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    fn rotate(&self, steps: usize) -> Direction8 {
+        Self::CLOCKWISE[(*self as usize + steps) % 8]
+    }
+
+    pub fn to_vector(&self) -> Vector2<i32> {
+        match self {
+            Direction8::Up => vector![0, -1],
+            Direction8::UpRight => vector![1, -1],
+            Direction8::Right => vector![1, 0],
+            Direction8::DownRight => vector![1, 1],
+            Direction8::Down => vector![0, 1],
+            Direction8::DownLeft => vector![-1, 1],
+            Direction8::Left => vector![-1, 0],
+            Direction8::UpLeft => vector![-1, -1],
+        }
+    }
+
+    // The direction a vector points to, if it lies on a row, column or diagonal
+    pub fn from_vector(vector: &Vector2<i32>) -> Option<Direction8> {
+        let on_line = vector.x == 0 || vector.y == 0 || vector.x.abs() == vector.y.abs();
+        let unit = vector.map(i32::signum);
+
+        Self::CLOCKWISE
+            .into_iter()
+            .find(|d| on_line && d.to_vector() == unit)
+    }
+
+    // Rotates by 45 degrees
+    pub fn clockwise(&self) -> Direction8 {
+        self.rotate(1)
+    }
+
+    pub fn counter_clockwise(&self) -> Direction8 {
+        self.rotate(7)
+    }
+
+    pub fn opposite(&self) -> Direction8 {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+
+    pub fn diagonals() -> impl Iterator<Item = Direction8> {
+        Direction8::iter().filter(|d| d.is_diagonal())
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+        }
+    }
+}
+
+// The positions at exactly `radius` steps from `center`, moving along rows and columns
+pub fn manhattan_ring(center: Vector2<i32>, radius: u32) -> impl Iterator<Item = Vector2<i32>> {
+    let radius = radius as i32;
+
+    (-radius..=radius).flat_map(move |x| {
+        let y = radius - x.abs();
+        let ys = if y == 0 { vec![0] } else { vec![y, -y] };
+        ys.into_iter().map(move |y| center + vector![x, y])
+    })
+}
+
+// The positions within `radius` steps from `center`, moving along rows and columns
+pub fn manhattan_ball(center: Vector2<i32>, radius: u32) -> impl Iterator<Item = Vector2<i32>> {
+    (0..=radius).flat_map(move |r| manhattan_ring(center, r))
+}
+
+// The positions on the border of the square of side `2 * radius + 1` around `center`
+pub fn chebyshev_ring(center: Vector2<i32>, radius: u32) -> impl Iterator<Item = Vector2<i32>> {
+    let radius = radius as i32;
+
+    (-radius..=radius)
+        .flat_map(move |y| (-radius..=radius).map(move |x| vector![x, y]))
+        .filter(move |offset| offset.x.abs().max(offset.y.abs()) == radius)
+        .map(move |offset| center + offset)
+}
@@ -0,0 +1,114 @@
+use crate::utils::geometry::{
+    chebyshev_ring, manhattan_ball, manhattan_ring, Direction, Direction8,
+};
+use crate::utils::nalgebra::MatrixHelpers;
+use nalgebra::{vector, DMatrix, Vector2};
+use std::collections::HashSet;
+use strum::IntoEnumIterator;
+
+fn sorted(points: impl Iterator<Item = Vector2<i32>>) -> Vec<(i32, i32)> {
+    let mut points: Vec<(i32, i32)> = points.map(|p| (p.x, p.y)).collect();
+    points.sort();
+    points
+}
+
+#[test]
+fn test_rotation() {
+    assert_eq!(Direction8::Up.clockwise(), Direction8::UpRight);
+    assert_eq!(Direction8::Up.counter_clockwise(), Direction8::UpLeft);
+    assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+
+    for direction in Direction8::iter() {
+        assert_eq!(direction.clockwise().counter_clockwise(), direction);
+        assert_eq!(direction.opposite().to_vector(), -direction.to_vector());
+        assert_eq!(
+            direction.clockwise().clockwise(),
+            direction.rotate(2),
+            "two rotations by 45 degrees turn {:?} by 90 degrees",
+            direction
+        );
+    }
+}
+
+#[test]
+fn test_vector_conversion() {
+    for direction in Direction8::iter() {
+        assert_eq!(
+            Direction8::from_vector(&direction.to_vector()),
+            Some(direction)
+        );
+    }
+
+    assert_eq!(
+        Direction8::from_vector(&vector![-3, 3]),
+        Some(Direction8::DownLeft)
+    );
+    assert_eq!(
+        Direction8::from_vector(&vector![0, 5]),
+        Some(Direction8::Down)
+    );
+    assert_eq!(Direction8::from_vector(&vector![2, 1]), None);
+    assert_eq!(Direction8::from_vector(&vector![0, 0]), None);
+}
+
+#[test]
+fn test_cardinal_directions() {
+    for direction in Direction::iter() {
+        let direction8 = Direction8::from(direction.clone());
+        assert!(!direction8.is_diagonal());
+        assert_eq!(direction8.to_vector(), direction.to_vector());
+    }
+
+    assert_eq!(Direction8::diagonals().count(), 4);
+}
+
+#[test]
+fn test_manhattan_ring() {
+    let center = vector![5, 5];
+
+    assert_eq!(sorted(manhattan_ring(center, 0)), vec![(5, 5)]);
+    assert_eq!(
+        sorted(manhattan_ring(center, 1)),
+        vec![(4, 5), (5, 4), (5, 6), (6, 5)]
+    );
+
+    for radius in 0..6 {
+        let ring: Vec<Vector2<i32>> = manhattan_ring(center, radius).collect();
+        let unique: HashSet<Vector2<i32>> = ring.iter().copied().collect();
+
+        assert_eq!(ring.len(), unique.len());
+        assert_eq!(
+            ring.len(),
+            if radius == 0 { 1 } else { 4 * radius as usize }
+        );
+        assert!(ring
+            .iter()
+            .all(|p| (p - center).abs().sum() == radius as i32));
+    }
+}
+
+#[test]
+fn test_manhattan_ball() {
+    assert_eq!(manhattan_ball(vector![0, 0], 2).count(), 13);
+}
+
+#[test]
+fn test_chebyshev_ring() {
+    assert_eq!(sorted(chebyshev_ring(vector![1, 1], 0)), vec![(1, 1)]);
+    assert_eq!(chebyshev_ring(vector![1, 1], 1).count(), 8);
+    assert_eq!(chebyshev_ring(vector![1, 1], 3).count(), 24);
+}
+
+#[test]
+fn test_stencil_within_matrix() {
+    let matrix = DMatrix::from_element(3, 3, 0);
+
+    assert_eq!(
+        sorted(
+            matrix
+                .valid_points(chebyshev_ring(vector![0, 0], 1))
+                .into_iter()
+        ),
+        vec![(0, 1), (1, 0), (1, 1)]
+    );
+}
@@ -0,0 +1,169 @@
+use crate::error::SolveError;
+use crate::utils::geometry::{Direction, Direction8};
+use crate::utils::nalgebra::{Markers, MatrixHelpers, MatrixParser, VectorHelpers};
+use nalgebra::{vector, DMatrix, Scalar, Vector2};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use strum::IntoEnumIterator;
+
+#[cfg(test)]
+mod test;
+
+// A tile that is drawn as a single character when the grid is displayed
+pub trait Tile {
+    fn to_char(&self) -> char;
+}
+
+impl Tile for char {
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+// A rectangular grid indexed by (x, y) positions, x growing to the right and y downwards
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    cells: DMatrix<T>,
+}
+
+impl<T: Scalar> Grid<T> {
+    pub fn new(width: usize, height: usize, tile: T) -> Self {
+        Self {
+            cells: DMatrix::from_element(height, width, tile),
+        }
+    }
+
+    pub fn from_matrix(cells: DMatrix<T>) -> Self {
+        Self { cells }
+    }
+
+    pub fn parse(input: &str, char_map: impl FnMut(char) -> Option<T>) -> Result<Self, SolveError> {
+        Ok(Self::from_matrix(input.try_to_matrix(char_map)?))
+    }
+
+    pub fn parse_with_markers(
+        input: &str,
+        markers: &[char],
+        char_map: impl FnMut(char) -> Option<T>,
+    ) -> Result<(Self, Markers), SolveError> {
+        let (cells, markers) = input.try_to_matrix_with_markers(markers, char_map)?;
+        Ok((Self::from_matrix(cells), markers))
+    }
+
+    pub fn matrix(&self) -> &DMatrix<T> {
+        &self.cells
+    }
+
+    // A grid of the same size with every tile converted by `f`
+    pub fn map<U: Scalar>(&self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid::from_matrix(self.cells.map(f))
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    pub fn contains(&self, position: &Vector2<i32>) -> bool {
+        self.cells.valid_index(position)
+    }
+
+    pub fn get(&self, position: &Vector2<i32>) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.to_matrix_index()])
+    }
+
+    pub fn get_mut(&mut self, position: &Vector2<i32>) -> Option<&mut T> {
+        match self.contains(position) {
+            true => Some(&mut self.cells[position.to_matrix_index()]),
+            false => None,
+        }
+    }
+
+    // All positions row by row, starting at the top left
+    pub fn positions(&self) -> impl Iterator<Item = Vector2<i32>> + '_ {
+        (0..self.height())
+            .flat_map(move |y| (0..self.width()).map(move |x| vector![x as i32, y as i32]))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2<i32>, &T)> + '_ {
+        self.positions().map(|p| (p, &self[p]))
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells.row(y).into_iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells.column(x).into_iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.height()).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width()).map(|x| self.column(x))
+    }
+
+    // The orthogonal neighbors that lie within the grid
+    pub fn neighbors4(&self, position: &Vector2<i32>) -> impl Iterator<Item = Vector2<i32>> + '_ {
+        let position = *position;
+        Direction::iter()
+            .map(move |d| position + d.to_vector())
+            .filter(|p| self.contains(p))
+    }
+
+    // The orthogonal and diagonal neighbors that lie within the grid
+    pub fn neighbors8(&self, position: &Vector2<i32>) -> impl Iterator<Item = Vector2<i32>> + '_ {
+        let position = *position;
+        Direction8::iter()
+            .map(move |d| position + d.to_vector())
+            .filter(|p| self.contains(p))
+    }
+
+    pub fn find(&self, tile: &T) -> Option<Vector2<i32>> {
+        self.find_all(tile).next()
+    }
+
+    pub fn find_all<'a>(&'a self, tile: &'a T) -> impl Iterator<Item = Vector2<i32>> + 'a {
+        self.iter().filter(move |(_, t)| *t == tile).map(|(p, _)| p)
+    }
+}
+
+impl<T: Scalar> Index<Vector2<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Vector2<i32>) -> &T {
+        self.get(&position).unwrap_or_else(|| {
+            panic!(
+                "position {},{} is outside of the grid",
+                position.x, position.y
+            )
+        })
+    }
+}
+
+impl<T: Scalar> IndexMut<Vector2<i32>> for Grid<T> {
+    fn index_mut(&mut self, position: Vector2<i32>) -> &mut T {
+        self.get_mut(&position).unwrap_or_else(|| {
+            panic!(
+                "position {},{} is outside of the grid",
+                position.x, position.y
+            )
+        })
+    }
+}
+
+impl<T: Scalar + Tile> fmt::Display for Grid<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(fmt, "{}", row.map(|t| t.to_char()).collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
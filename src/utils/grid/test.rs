@@ -0,0 +1,105 @@
+use crate::utils::grid::Grid;
+use nalgebra::vector;
+
+static SAMPLE: &str = r#"
+ab#
+#.c
+"#;
+
+fn sample() -> Grid<char> {
+    Grid::parse(SAMPLE, |c| (c != '?').then_some(c)).unwrap()
+}
+
+#[test]
+fn test_parse() {
+    let grid = sample();
+
+    assert_eq!(grid.width(), 3);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid[vector![1, 0]], 'b');
+    assert_eq!(grid[vector![2, 1]], 'c');
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(Grid::parse("ab\nc?", |c| (c != '?').then_some(c)).is_err());
+    assert!(Grid::parse("ab\nc", Some).is_err());
+    assert!(Grid::parse("", Some).is_err());
+}
+
+#[test]
+fn test_get() {
+    let mut grid = sample();
+
+    assert_eq!(grid.get(&vector![0, 1]), Some(&'#'));
+    assert_eq!(grid.get(&vector![3, 0]), None);
+    assert_eq!(grid.get(&vector![0, -1]), None);
+
+    grid[vector![1, 1]] = 'x';
+    assert_eq!(grid.get(&vector![1, 1]), Some(&'x'));
+}
+
+#[test]
+#[should_panic(expected = "outside of the grid")]
+fn test_index_outside() {
+    let _ = sample()[vector![0, 2]];
+}
+
+#[test]
+fn test_neighbors() {
+    let grid = sample();
+
+    let mut neighbors4: Vec<_> = grid.neighbors4(&vector![0, 0]).collect();
+    neighbors4.sort_by_key(|p| (p.y, p.x));
+    assert_eq!(neighbors4, vec![vector![1, 0], vector![0, 1]]);
+
+    let mut neighbors8: Vec<_> = grid.neighbors8(&vector![1, 0]).collect();
+    neighbors8.sort_by_key(|p| (p.y, p.x));
+    assert_eq!(
+        neighbors8,
+        vec![
+            vector![0, 0],
+            vector![2, 0],
+            vector![0, 1],
+            vector![1, 1],
+            vector![2, 1]
+        ]
+    );
+}
+
+#[test]
+fn test_rows_and_columns() {
+    let grid = sample();
+
+    let rows: Vec<String> = grid.rows().map(|row| row.collect()).collect();
+    assert_eq!(rows, vec!["ab#", "#.c"]);
+
+    let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+    assert_eq!(columns, vec!["a#", "b.", "#c"]);
+}
+
+#[test]
+fn test_find() {
+    let grid = sample();
+
+    assert_eq!(grid.find(&'c'), Some(vector![2, 1]));
+    assert_eq!(grid.find(&'z'), None);
+    assert_eq!(
+        grid.find_all(&'#').collect::<Vec<_>>(),
+        vec![vector![2, 0], vector![0, 1]]
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(sample().to_string(), "ab#\n#.c\n");
+}
+
+#[test]
+fn test_map() {
+    let walls = sample().map(|c| c == '#');
+
+    assert_eq!((walls.width(), walls.height()), (3, 2));
+    assert!(walls[vector![2, 0]]);
+    assert!(!walls[vector![1, 1]]);
+}
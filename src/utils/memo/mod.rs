@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+// A memoization cache owned by a solver, so results never outlive the input they were computed for
+#[derive(Debug)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            values: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+        match value {
+            Some(_) => self.hits += 1,
+            None => self.misses += 1,
+        }
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> V {
+        self.values.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            entries: self.values.len(),
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "{} entries, {} hits, {} misses",
+            self.entries, self.hits, self.misses
+        )
+    }
+}
@@ -1,2 +1,8 @@
-pub(crate) mod geometry;
-pub(crate) mod nalgebra;
+pub mod geometry;
+pub mod grid;
+pub mod memo;
+pub mod nalgebra;
+pub mod parsing;
+pub mod random;
+pub mod render;
+pub mod search;
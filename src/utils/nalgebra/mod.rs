@@ -1,47 +1,186 @@
+use crate::error::SolveError;
 use nalgebra::{vector, DMatrix, Matrix, Scalar, Storage, Vector2};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
 
-pub trait MatrixParser<T> {
-    fn to_matrix(&self, char_map: fn(char) -> T) -> DMatrix<T>;
+#[cfg(test)]
+mod test;
+
+// Positions of marker characters such as `S` or `@`, recorded while parsing a grid
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Markers {
+    positions: HashMap<char, Vec<Vector2<i32>>>,
+}
+
+impl Markers {
+    // All positions of `marker`, row by row
+    pub fn all(&self, marker: char) -> &[Vector2<i32>] {
+        self.positions
+            .get(&marker)
+            .map_or(&[], |positions| positions)
+    }
+
+    pub fn first(&self, marker: char) -> Option<Vector2<i32>> {
+        self.all(marker).first().copied()
+    }
 }
 
-impl<T: PartialEq + Clone + std::fmt::Debug + 'static> MatrixParser<T> for String {
-    fn to_matrix(&self, char_map: fn(char) -> T) -> DMatrix<T> {
-        let elements: Vec<Vec<T>> = self
-            .trim()
-            .lines()
-            .map(|l| l.trim())
-            .map(|l| l.chars().map(|c| char_map(c)).collect())
-            .collect();
+pub trait MatrixParser {
+    // Errors point at the line and column of the first character `char_map` rejects
+    fn try_to_matrix<T: Scalar>(
+        &self,
+        char_map: impl FnMut(char) -> Option<T>,
+    ) -> Result<DMatrix<T>, SolveError>;
+
+    // Also records the positions of the characters in `markers`
+    fn try_to_matrix_with_markers<T: Scalar>(
+        &self,
+        markers: &[char],
+        char_map: impl FnMut(char) -> Option<T>,
+    ) -> Result<(DMatrix<T>, Markers), SolveError>;
+}
+
+impl MatrixParser for str {
+    fn try_to_matrix<T: Scalar>(
+        &self,
+        char_map: impl FnMut(char) -> Option<T>,
+    ) -> Result<DMatrix<T>, SolveError> {
+        self.try_to_matrix_with_markers(&[], char_map)
+            .map(|(matrix, _)| matrix)
+    }
+
+    fn try_to_matrix_with_markers<T: Scalar>(
+        &self,
+        markers: &[char],
+        mut char_map: impl FnMut(char) -> Option<T>,
+    ) -> Result<(DMatrix<T>, Markers), SolveError> {
+        let lines: Vec<&str> = self.trim().lines().map(|l| l.trim()).collect();
 
-        let rows = elements.len();
-        let columns = elements.first().map_or(0, |l| l.len());
+        let rows = lines.len();
+        let columns = lines.first().map_or(0, |l| l.chars().count());
 
-        if elements.iter().any(|l| l.len() != columns) {
-            panic!("Not all lines have the same length.")
+        if rows == 0 {
+            return Err(SolveError::unexpected_end(self, "expected a grid, found no input"));
         }
 
-        let flattened = elements
-            .iter()
-            .flatten()
-            .map(|c| c.clone())
-            .collect::<Vec<T>>();
+        let mut found = Markers::default();
+        let mut elements: Vec<T> = Vec::with_capacity(rows * columns);
+        for (y, line) in lines.iter().enumerate() {
+            if line.chars().count() != columns {
+                return Err(SolveError::invalid_input(
+                    self,
+                    line,
+                    format!("expected a line of length {}, found '{}'", columns, line),
+                ));
+            }
+
+            for (x, (index, c)) in line.char_indices().enumerate() {
+                let element = char_map(c).ok_or_else(|| {
+                    SolveError::invalid_input(
+                        self,
+                        &line[index..],
+                        format!("unexpected character '{}'", c),
+                    )
+                })?;
+                elements.push(element);
+
+                if markers.contains(&c) {
+                    found
+                        .positions
+                        .entry(c)
+                        .or_default()
+                        .push(vector![x as i32, y as i32]);
+                }
+            }
+        }
 
-        DMatrix::from_row_iterator(rows, columns, flattened)
+        Ok((DMatrix::from_row_iterator(rows, columns, elements), found))
     }
 }
 
-pub trait VectorHelpers {
+// The signed integers positions can be made of, such as `i32` for grids and `i64` for the claw
+// machines of day 13
+pub trait SignedInteger:
+    Scalar
+    + Copy
+    + Ord
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    // `None` for negative values, which have no index
+    fn to_index(self) -> Option<usize>;
+    fn from_index(index: usize) -> Self;
+}
+
+macro_rules! impl_signed_integer {
+    ($($t:ty),*) => {
+        $(
+            impl SignedInteger for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn to_index(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+
+                fn from_index(index: usize) -> Self {
+                    <$t>::try_from(index).unwrap_or_else(|_| {
+                        panic!("index {} does not fit in an {}", index, stringify!($t))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_integer!(i8, i16, i32, i64, isize);
+
+pub trait VectorHelpers: Sized {
+    type Coordinate;
+
+    // The (row, column) index, `None` when a coordinate is negative
+    fn try_to_matrix_index(&self) -> Option<(usize, usize)>;
     fn to_matrix_index(&self) -> (usize, usize);
 
-    fn get_intermediate_points(&self, end: &Vector2<i32>) -> Vec<Vector2<i32>>;
+    fn get_intermediate_points(&self, end: &Self) -> Vec<Self>;
+
+    fn manhattan_distance(&self, other: &Self) -> Self::Coordinate;
+    // The orthogonal neighbors, in the order of `Direction::iter`
+    fn neighbors4(&self) -> [Self; 4];
+    // Whether the position lies in the rectangle spanned by `min` and `max`, both inclusive
+    fn is_within(&self, min: &Self, max: &Self) -> bool;
 }
 
-impl VectorHelpers for Vector2<i32> {
+impl<N: SignedInteger> VectorHelpers for Vector2<N> {
+    type Coordinate = N;
+
+    fn try_to_matrix_index(&self) -> Option<(usize, usize)> {
+        Some((self.y.to_index()?, self.x.to_index()?))
+    }
+
     fn to_matrix_index(&self) -> (usize, usize) {
-        (self.y as usize, self.x as usize)
+        self.try_to_matrix_index().unwrap_or_else(|| {
+            panic!(
+                "position {},{} has a negative coordinate and no matrix index",
+                self.x, self.y
+            )
+        })
     }
 
-    fn get_intermediate_points(&self, end: &Vector2<i32>) -> Vec<Vector2<i32>> {
+    fn get_intermediate_points(&self, end: &Vector2<N>) -> Vec<Vector2<N>> {
         let mut points = Vec::new();
 
         let start = self;
@@ -52,8 +191,8 @@ impl VectorHelpers for Vector2<i32> {
         let delta_x = (end.x - start.x).abs();
         let delta_y = -(end.y - start.y).abs();
 
-        let step_x = if start.x < end.x { 1 } else { -1 };
-        let step_y = if start.y < end.y { 1 } else { -1 };
+        let step_x = if start.x < end.x { N::ONE } else { -N::ONE };
+        let step_y = if start.y < end.y { N::ONE } else { -N::ONE };
 
         let mut error_term = delta_x + delta_y;
 
@@ -64,7 +203,7 @@ impl VectorHelpers for Vector2<i32> {
                 break;
             }
 
-            let double_error = 2 * error_term;
+            let double_error = error_term + error_term;
             if double_error >= delta_y {
                 error_term += delta_y;
                 current_x += step_x;
@@ -77,13 +216,37 @@ impl VectorHelpers for Vector2<i32> {
 
         points
     }
+
+    fn manhattan_distance(&self, other: &Vector2<N>) -> N {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    fn neighbors4(&self) -> [Vector2<N>; 4] {
+        let (x, y) = (self.x, self.y);
+        [
+            vector![x, y - N::ONE],
+            vector![x, y + N::ONE],
+            vector![x - N::ONE, y],
+            vector![x + N::ONE, y],
+        ]
+    }
+
+    fn is_within(&self, min: &Vector2<N>, max: &Vector2<N>) -> bool {
+        (min.x..=max.x).contains(&self.x) && (min.y..=max.y).contains(&self.y)
+    }
 }
 
+// Positions are (x, y) vectors of any `SignedInteger`, those outside the matrix have no element
 pub trait MatrixHelpers<T> {
-    fn at(&self, location: &Vector2<i32>) -> &T;
-    fn at_value(&self, location: &Vector2<i32>) -> T;
-    fn valid_index(&self, l: &Vector2<i32>) -> bool;
-    fn find_index(&self, element: T) -> Option<Vector2<i32>>;
+    fn at<N: SignedInteger>(&self, location: &Vector2<N>) -> &T;
+    fn at_value<N: SignedInteger>(&self, location: &Vector2<N>) -> T;
+    fn valid_index<N: SignedInteger>(&self, l: &Vector2<N>) -> bool;
+    fn find_index<N: SignedInteger>(&self, element: T) -> Option<Vector2<N>>;
+    // Keeps the positions of a stencil, such as a `geometry::manhattan_ring`, that lie in the matrix
+    fn valid_points<N: SignedInteger>(
+        &self,
+        points: impl IntoIterator<Item = Vector2<N>>,
+    ) -> Vec<Vector2<N>>;
 }
 
 impl<T, R, C, S> MatrixHelpers<T> for Matrix<T, R, C, S>
@@ -93,21 +256,30 @@ where
     C: nalgebra::Dim,
     S: Storage<T, R, C>,
 {
-    fn at(&self, location: &Vector2<i32>) -> &T {
+    fn at<N: SignedInteger>(&self, location: &Vector2<N>) -> &T {
         &self[location.to_matrix_index()]
     }
 
-    fn at_value(&self, location: &Vector2<i32>) -> T {
+    fn at_value<N: SignedInteger>(&self, location: &Vector2<N>) -> T {
         self[location.to_matrix_index()].clone()
     }
 
-    fn valid_index(&self, l: &Vector2<i32>) -> bool {
-        l.x >= 0 && l.x < self.ncols() as i32 && l.y >= 0 && l.y < self.nrows() as i32
+    fn valid_index<N: SignedInteger>(&self, l: &Vector2<N>) -> bool {
+        l.try_to_matrix_index()
+            .is_some_and(|(row, column)| row < self.nrows() && column < self.ncols())
     }
 
-    fn find_index(&self, element: T) -> Option<Vector2<i32>> {
+    fn find_index<N: SignedInteger>(&self, element: T) -> Option<Vector2<N>> {
         (0..self.ncols())
-            .flat_map(|x| (0..self.nrows()).map(move |y| vector![x as i32, y as i32]))
-            .find(|v: &Vector2<i32>| *self.at(v) == element)
+            .flat_map(|x| (0..self.nrows()).map(move |y| (x, y)))
+            .find(|&(x, y)| self[(y, x)] == element)
+            .map(|(x, y)| vector![N::from_index(x), N::from_index(y)])
+    }
+
+    fn valid_points<N: SignedInteger>(
+        &self,
+        points: impl IntoIterator<Item = Vector2<N>>,
+    ) -> Vec<Vector2<N>> {
+        points.into_iter().filter(|p| self.valid_index(p)).collect()
     }
 }
@@ -0,0 +1,123 @@
+use crate::error::SolveError;
+use crate::utils::nalgebra::{MatrixHelpers, MatrixParser, VectorHelpers};
+use nalgebra::{vector, DMatrix, Vector2};
+
+fn location(error: SolveError) -> (usize, usize) {
+    match error {
+        SolveError::InvalidInput { line, column, .. } => (line, column),
+        error => panic!("expected an invalid input error, got {}", error),
+    }
+}
+
+#[test]
+fn test_try_to_matrix() {
+    let matrix = "12\n34".try_to_matrix(|c| c.to_digit(10)).unwrap();
+
+    assert_eq!(matrix.shape(), (2, 2));
+    assert_eq!(matrix[(1, 0)], 3);
+}
+
+#[test]
+fn test_try_to_matrix_from_string() {
+    let matrix = String::from("ab\ncd").try_to_matrix(Some).unwrap();
+
+    assert_eq!(matrix[(0, 1)], 'b');
+}
+
+#[test]
+fn test_try_to_matrix_closure() {
+    let mut seen = 0;
+    let matrix = "#.#\n..#"
+        .try_to_matrix(|c| {
+            seen += 1;
+            Some(c == '#')
+        })
+        .unwrap();
+
+    assert_eq!(seen, 6);
+    assert!(matrix[(1, 2)]);
+}
+
+#[test]
+fn test_try_to_matrix_bad_character() {
+    let error = "\n12\n3x\n".try_to_matrix(|c| c.to_digit(10)).unwrap_err();
+
+    assert_eq!(location(error), (3, 2));
+}
+
+#[test]
+fn test_try_to_matrix_bad_length() {
+    let error = "123\n45\n".try_to_matrix(|c| c.to_digit(10)).unwrap_err();
+
+    assert_eq!(location(error), (2, 1));
+}
+
+#[test]
+fn test_try_to_matrix_with_markers() {
+    let (matrix, markers) = "S.#\n.#E\n..E"
+        .try_to_matrix_with_markers(&['S', 'E'], |c| Some(c == '#'))
+        .unwrap();
+
+    assert_eq!(matrix.shape(), (3, 3));
+    assert_eq!(markers.first('S'), Some(vector![0, 0]));
+    assert_eq!(markers.all('E'), &[vector![2, 1], vector![2, 2]]);
+    assert_eq!(markers.first('#'), None);
+}
+
+#[test]
+fn test_matrix_index() {
+    assert_eq!(vector![3, 1].to_matrix_index(), (1, 3));
+    assert_eq!(vector![3i64, 1].try_to_matrix_index(), Some((1, 3)));
+    assert_eq!(vector![-1i64, 1].try_to_matrix_index(), None);
+    assert_eq!(vector![0i8, -5].try_to_matrix_index(), None);
+}
+
+#[test]
+#[should_panic(expected = "negative coordinate")]
+fn test_negative_matrix_index() {
+    vector![-1, 0].to_matrix_index();
+}
+
+#[test]
+fn test_valid_index() {
+    let matrix = DMatrix::from_element(2, 3, 0);
+
+    assert!(matrix.valid_index(&vector![2, 1]));
+    assert!(!matrix.valid_index(&vector![3, 1]));
+    assert!(!matrix.valid_index(&vector![-1, 0]));
+    // Coordinates that do not fit an i32 are not wrapped into the matrix
+    assert!(!matrix.valid_index(&vector![1i64 << 32, 0]));
+    assert!(matrix.valid_index(&vector![1i64, 1]));
+
+    assert_eq!(matrix.find_index(0), Some(vector![0i64, 0]));
+    assert_eq!(matrix.find_index::<i32>(1), None);
+}
+
+#[test]
+fn test_intermediate_points() {
+    let start: Vector2<i64> = vector![0, 0];
+
+    assert_eq!(
+        start.get_intermediate_points(&vector![0, 3]),
+        vec![vector![0, 0], vector![0, 1], vector![0, 2], vector![0, 3]]
+    );
+    assert_eq!(
+        vector![2i32, -2].get_intermediate_points(&vector![0, 0]),
+        vec![vector![2, -2], vector![1, -1], vector![0, 0]]
+    );
+    assert_eq!(start.get_intermediate_points(&start), vec![start]);
+}
+
+#[test]
+fn test_distances_and_bounds() {
+    let position: Vector2<i64> = vector![10_000_000_000, -3];
+
+    assert_eq!(position.manhattan_distance(&vector![0, 0]), 10_000_000_003);
+    assert_eq!(
+        vector![1, 1].neighbors4(),
+        [vector![1, 0], vector![1, 2], vector![0, 1], vector![2, 1]]
+    );
+
+    assert!(position.is_within(&vector![0, -3], &vector![i64::MAX, 0]));
+    assert!(!position.is_within(&vector![0, 0], &vector![i64::MAX, i64::MAX]));
+}
@@ -0,0 +1,112 @@
+use crate::error::SolveError;
+use std::io::BufRead;
+use std::str::FromStr;
+
+// All helpers return slices of `input`, so errors can point at the offending line and column.
+
+pub fn non_empty_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(|l| l.trim()).filter(|l| !l.is_empty())
+}
+
+// Streams the non-empty lines of `reader` into `f`, reusing one buffer. Errors of `f` are relative
+// to the line it was given and are moved to the line's position in the input.
+pub fn for_each_line(
+    reader: &mut dyn BufRead,
+    mut f: impl FnMut(&str) -> Result<(), SolveError>,
+) -> Result<(), SolveError> {
+    let mut line = String::new();
+    let mut number = 0;
+
+    loop {
+        line.clear();
+        number += 1;
+
+        let read = reader.read_line(&mut line).map_err(|e| {
+            SolveError::unexpected_end(&line, format!("failed to read the input: {}", e))
+                .on_line(number)
+        })?;
+        if read == 0 {
+            return Ok(());
+        }
+
+        let content = line.trim_end_matches(['\n', '\r']);
+        if !content.trim().is_empty() {
+            f(content).map_err(|e| e.on_line(number))?;
+        }
+    }
+}
+
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if !line.trim().is_empty() {
+            start.get_or_insert(offset);
+            end = offset + line.len();
+        } else if let Some(section_start) = start.take() {
+            sections.push(input[section_start..end].trim());
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(section_start) = start {
+        sections.push(input[section_start..end].trim());
+    }
+
+    sections
+}
+
+pub fn parse_number<T: FromStr>(input: &str, token: &str) -> Result<T, SolveError> {
+    let token = token.trim();
+    token.parse::<T>().map_err(|_| {
+        SolveError::invalid_input(
+            input,
+            token,
+            format!("expected a number, found '{}'", token),
+        )
+    })
+}
+
+pub fn split_pair<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), SolveError> {
+    text.split_once(delimiter).ok_or_else(|| {
+        SolveError::invalid_input(
+            input,
+            text,
+            format!("expected '{}' in '{}'", delimiter, text),
+        )
+    })
+}
+
+pub fn expect_sections<const N: usize>(input: &str) -> Result<[&str; N], SolveError> {
+    let sections = sections(input);
+    let found = sections.len();
+
+    if let Some(extra) = sections.get(N) {
+        return Err(SolveError::invalid_input(
+            input,
+            extra,
+            format!(
+                "expected {} blank-line separated sections, found {}",
+                N, found
+            ),
+        ));
+    }
+
+    sections.try_into().map_err(|_| {
+        SolveError::unexpected_end(
+            input,
+            format!(
+                "expected {} blank-line separated sections, found {}",
+                N, found
+            ),
+        )
+    })
+}
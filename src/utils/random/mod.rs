@@ -0,0 +1,62 @@
+use std::ops::RangeInclusive;
+
+// A small deterministic generator (SplitMix64), the same seed always gives the same sequence
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    // A value in `0..bound`, the bias for large bounds is negligible for generating inputs
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "the bound must be positive");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    pub fn range<T>(&mut self, range: RangeInclusive<T>) -> T
+    where
+        T: Copy + TryInto<i128> + TryFrom<i128>,
+    {
+        let to_wide = |value: T| value.try_into().unwrap_or_else(|_| unreachable!());
+        let (start, end) = (to_wide(*range.start()), to_wide(*range.end()));
+        assert!(start <= end, "the range must not be empty");
+
+        let offset = match u64::try_from(end - start) {
+            Ok(u64::MAX) => self.next_u64(),
+            Ok(span) => self.below(span + 1),
+            Err(_) => panic!("the range is too large"),
+        };
+        T::try_from(start + offset as i128).unwrap_or_else(|_| unreachable!())
+    }
+
+    pub fn float(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.float() < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
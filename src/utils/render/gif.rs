@@ -0,0 +1,182 @@
+// A minimal encoder for animated GIFs. All frames share one global palette, so an animation can
+// use at most 256 distinct colors.
+
+use crate::utils::render::Color;
+use std::collections::HashMap;
+
+// LZW codes are at most 12 bits wide
+const MAX_CODE_WIDTH: u8 = 12;
+const MAX_CODES: u16 = 1 << MAX_CODE_WIDTH;
+
+// Packs codes of varying width into bytes, least significant bit first
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u8) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += width;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+// Compresses palette indices the way GIF decoders expect: the code width grows one code after
+// the table reaches the next power of two, and a full table is cleared
+pub fn lzw(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    let mut writer = BitWriter::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut width = min_code_size + 1;
+    let mut next = end + 1;
+    let mut prefix: Option<u16> = None;
+
+    writer.write(clear, width);
+
+    for &index in indices {
+        let Some(code) = prefix else {
+            prefix = Some(index as u16);
+            continue;
+        };
+
+        if let Some(&extended) = table.get(&(code, index)) {
+            prefix = Some(extended);
+            continue;
+        }
+
+        writer.write(code, width);
+        table.insert((code, index), next);
+        next += 1;
+
+        if next > 1 << width && width < MAX_CODE_WIDTH {
+            width += 1;
+        }
+
+        if next == MAX_CODES {
+            writer.write(clear, width);
+            table.clear();
+            width = min_code_size + 1;
+            next = end + 1;
+        }
+
+        prefix = Some(index as u16);
+    }
+
+    if let Some(code) = prefix {
+        writer.write(code, width);
+        // The decoder adds an entry for the last code, which may widen the end code
+        if next == 1 << width && width < MAX_CODE_WIDTH {
+            width += 1;
+        }
+    }
+
+    writer.write(end, width);
+    writer.finish()
+}
+
+// Data is split into blocks of at most 255 bytes, each prefixed with its length
+fn sub_blocks(gif: &mut Vec<u8>, data: &[u8]) {
+    for block in data.chunks(255) {
+        gif.push(block.len() as u8);
+        gif.extend(block);
+    }
+    gif.push(0);
+}
+
+// `frames` hold one color per pixel, row by row. `delay` is the time each frame is shown, in
+// hundredths of a second.
+pub fn encode(
+    width: usize,
+    height: usize,
+    frames: &[&[Color]],
+    delay: u16,
+) -> Result<Vec<u8>, String> {
+    let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(format!(
+            "An animation of {}x{} pixels is too large, at most 65535 pixels per side fit",
+            width, height
+        ));
+    };
+
+    let mut palette: Vec<Color> = vec![];
+    let mut palette_index: HashMap<Color, u8> = HashMap::new();
+    let mut frame_indices = vec![];
+
+    for frame in frames {
+        assert_eq!(
+            frame.len(),
+            width as usize * height as usize,
+            "expected one color per pixel"
+        );
+
+        let mut indices = Vec::with_capacity(frame.len());
+        for color in frame.iter() {
+            let index = match palette_index.get(color) {
+                Some(&index) => index,
+                None if palette.len() < 256 => {
+                    palette.push(*color);
+                    palette_index.insert(*color, (palette.len() - 1) as u8);
+                    (palette.len() - 1) as u8
+                }
+                None => return Err("An animation can use at most 256 colors".into()),
+            };
+            indices.push(index);
+        }
+        frame_indices.push(indices);
+    }
+
+    // The palette size is a power of two, and LZW codes start at least 2 bits wide
+    let mut bits = 2;
+    while 1 << bits < palette.len() {
+        bits += 1;
+    }
+    palette.resize(1 << bits, [0, 0, 0]);
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(width.to_le_bytes());
+    gif.extend(height.to_le_bytes());
+    // A global palette with 8 bits per primary color, background color 0, square pixels
+    gif.extend([0xF0 | (bits - 1), 0, 0]);
+    gif.extend(palette.iter().flatten());
+
+    // Loops the animation forever
+    gif.extend([0x21, 0xFF, 0x0B]);
+    gif.extend(b"NETSCAPE2.0");
+    gif.extend([0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    for indices in frame_indices {
+        gif.extend([0x21, 0xF9, 0x04, 0x00]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0x00, 0x00]);
+
+        // The frame covers the whole image and has no local palette
+        gif.push(0x2C);
+        gif.extend([0, 0, 0, 0]);
+        gif.extend(width.to_le_bytes());
+        gif.extend(height.to_le_bytes());
+        gif.push(0x00);
+
+        gif.push(bits);
+        sub_blocks(&mut gif, &lzw(&indices, bits));
+    }
+
+    gif.push(0x3B);
+    Ok(gif)
+}
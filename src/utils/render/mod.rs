@@ -0,0 +1,167 @@
+use crate::utils::grid::Grid;
+use crate::utils::nalgebra::VectorHelpers;
+use nalgebra::{DMatrix, Scalar, Vector2};
+use std::fs;
+use std::path::Path;
+
+mod gif;
+mod png;
+mod record;
+mod terminal;
+#[cfg(test)]
+mod test;
+
+pub use record::{Frame, Recorder};
+pub use terminal::{Canvas, ColorMode};
+
+pub type Color = [u8; 3];
+
+pub const BLACK: Color = [0, 0, 0];
+pub const WHITE: Color = [255, 255, 255];
+pub const GRAY: Color = [96, 96, 96];
+pub const RED: Color = [220, 40, 40];
+pub const GREEN: Color = [40, 180, 60];
+pub const BLUE: Color = [50, 100, 220];
+pub const GOLD: Color = [240, 190, 30];
+
+// Colors for any number of groups, e.g. one per plant. Consecutive indices are spread around the
+// color wheel by the golden angle, so nearby indices never look alike.
+pub fn group_color(index: usize) -> Color {
+    let hue = (index as f64 * 0.618_033_988_749_895).fract() * 6.0;
+    let (saturation, value) = (0.65, 0.95);
+
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    let m = value - chroma;
+    [r, g, b].map(|c| ((c + m) * 255.0).round() as u8)
+}
+
+// The color a tile is drawn with, the palette of a grid of these tiles
+pub trait Colored {
+    fn color(&self) -> Color;
+}
+
+// An RGB image with one pixel per tile, until it is scaled
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Color>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn from_matrix<T: Scalar>(matrix: &DMatrix<T>, palette: impl Fn(&T) -> Color) -> Self {
+        let mut image = Self::new(matrix.ncols(), matrix.nrows(), BLACK);
+        for (y, row) in matrix.row_iter().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                image.pixels[y * image.width + x] = palette(tile);
+            }
+        }
+        image
+    }
+
+    pub fn from_grid<T: Scalar>(grid: &Grid<T>, palette: impl Fn(&T) -> Color) -> Self {
+        Self::from_matrix(grid.matrix(), palette)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, position: &Vector2<i32>) -> Option<usize> {
+        let (row, column) = position
+            .try_to_matrix_index()
+            .filter(|&(row, column)| row < self.height && column < self.width)?;
+        Some(row * self.width + column)
+    }
+
+    pub fn get(&self, position: &Vector2<i32>) -> Option<Color> {
+        self.index(position).map(|index| self.pixels[index])
+    }
+
+    // Positions outside of the image are ignored
+    pub fn set(&mut self, position: &Vector2<i32>, color: Color) {
+        if let Some(index) = self.index(position) {
+            self.pixels[index] = color;
+        }
+    }
+
+    // Draws a path, or any other set of positions, over the image
+    pub fn with_overlay(
+        mut self,
+        positions: impl IntoIterator<Item = Vector2<i32>>,
+        color: Color,
+    ) -> Self {
+        for position in positions {
+            self.set(&position, color);
+        }
+        self
+    }
+
+    // Draws every pixel as a `factor` by `factor` square
+    pub fn scaled(&self, factor: usize) -> Self {
+        let width = self.width * factor;
+        let pixels = (0..self.height * factor)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+
+        Self {
+            width,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    fn rgb(&self) -> Vec<u8> {
+        self.pixels.iter().flatten().copied().collect()
+    }
+
+    // A binary PPM (P6), the simplest format most image viewers open
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.rgb());
+        ppm
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        png::encode(self.width, self.height, &self.rgb())
+    }
+
+    // The format is chosen by the extension of `path`, either `.png` or `.ppm`
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => {
+                return Err(format!(
+                    "Unknown image format of '{}', expected a .png or .ppm file",
+                    path.display()
+                ))
+            }
+        };
+
+        fs::write(path, bytes).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+    }
+}
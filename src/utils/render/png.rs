@@ -0,0 +1,91 @@
+// A minimal PNG encoder for 8-bit RGB images. The pixel data is stored in uncompressed deflate
+// blocks, which keeps the encoder small at the cost of larger files.
+
+use std::iter;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+// The largest number of bytes in a stored deflate block
+const MAX_BLOCK: usize = 0xFFFF;
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xEDB88320,
+                _ => crc >> 1,
+            };
+        }
+    }
+    !crc
+}
+
+pub fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    b << 16 | a
+}
+
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+
+    png.extend(crc.to_be_bytes());
+}
+
+pub fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate without a preset dictionary, at the lowest compression level
+    let mut stream = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = match data.is_empty() {
+        true => vec![&[]],
+        false => data.chunks(MAX_BLOCK).collect(),
+    };
+
+    for (i, block) in blocks.iter().enumerate() {
+        let last = i == blocks.len() - 1;
+        let length = block.len() as u16;
+
+        stream.push(last as u8);
+        stream.extend(length.to_le_bytes());
+        stream.extend((!length).to_le_bytes());
+        stream.extend(*block);
+    }
+
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+// `rgb` holds three bytes per pixel, row by row
+pub fn encode(width: usize, height: usize, rgb: &[u8]) -> Vec<u8> {
+    assert_eq!(
+        rgb.len(),
+        width * height * 3,
+        "expected three bytes per pixel"
+    );
+
+    let mut header = vec![];
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // Bit depth 8, truecolor, default compression and filtering, no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+
+    // Every scanline starts with its filter type, which is none
+    let row = width * 3;
+    let scanlines: Vec<u8> = (0..height)
+        .flat_map(|y| iter::once(0).chain(rgb[y * row..(y + 1) * row].iter().copied()))
+        .collect();
+
+    let mut png = SIGNATURE.to_vec();
+    chunk(&mut png, b"IHDR", &header);
+    chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
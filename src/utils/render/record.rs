@@ -0,0 +1,117 @@
+use crate::utils::grid::{Grid, Tile};
+use crate::utils::render::{gif, Colored, Image};
+use nalgebra::Scalar;
+use std::fs;
+use std::path::Path;
+
+// One state of a simulation, drawn as an image and as text
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub image: Image,
+    pub text: String,
+}
+
+impl Frame {
+    pub fn new(image: Image, text: String) -> Self {
+        Self { image, text }
+    }
+
+    pub fn from_grid<T: Scalar + Tile + Colored>(grid: &Grid<T>) -> Self {
+        Self::new(Image::from_grid(grid, T::color), grid.to_string())
+    }
+}
+
+// Collects the frames simulations push while they run. Only every `every`-th step is recorded,
+// up to `max_frames` frames, so long simulations stay cheap to record.
+#[derive(Debug)]
+pub struct Recorder {
+    every: usize,
+    max_frames: usize,
+    steps: usize,
+    frames: Vec<(usize, Frame)>,
+}
+
+impl Recorder {
+    pub fn new(every: usize, max_frames: usize) -> Self {
+        assert!(every > 0, "expected to record at least every step");
+
+        Self {
+            every,
+            max_frames,
+            steps: 0,
+            frames: vec![],
+        }
+    }
+
+    // A recorder that never draws a frame, for simulations that run without being recorded
+    pub fn disabled() -> Self {
+        Self::new(1, 0)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.frames.len() >= self.max_frames
+    }
+
+    // Counts a step of the simulation, `draw` is only called for the steps that are recorded
+    pub fn push(&mut self, draw: impl FnOnce() -> Frame) {
+        let step = self.steps;
+        self.steps += 1;
+
+        if step.is_multiple_of(self.every) && !self.is_full() {
+            self.frames.push((step, draw()));
+        }
+    }
+
+    // The recorded frames with the step they were recorded at
+    pub fn frames(&self) -> &[(usize, Frame)] {
+        &self.frames
+    }
+
+    pub fn to_text(&self) -> String {
+        self.frames
+            .iter()
+            .map(|(step, frame)| format!("step {}:\n{}", step, frame.text))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Every pixel is drawn as a `scale` by `scale` square, every frame is shown for `delay`
+    // hundredths of a second
+    pub fn to_gif(&self, scale: usize, delay: u16) -> Result<Vec<u8>, String> {
+        let images: Vec<Image> = self
+            .frames
+            .iter()
+            .map(|(_, frame)| frame.image.scaled(scale))
+            .collect();
+
+        let Some(first) = images.first() else {
+            return Err("No frames were recorded".into());
+        };
+        if images
+            .iter()
+            .any(|i| (i.width, i.height) != (first.width, first.height))
+        {
+            return Err("All frames of an animation must have the same size".into());
+        }
+
+        let pixels: Vec<&[_]> = images.iter().map(|i| i.pixels.as_slice()).collect();
+        gif::encode(first.width, first.height, &pixels, delay)
+    }
+
+    // The format is chosen by the extension of `path`, either an animated `.gif` or a `.txt`
+    // file with one frame after the other
+    pub fn save(&self, path: &Path, scale: usize, delay: u16) -> Result<(), String> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("gif") => self.to_gif(scale, delay)?,
+            Some("txt") => self.to_text().into_bytes(),
+            _ => {
+                return Err(format!(
+                    "Unknown recording format of '{}', expected a .gif or .txt file",
+                    path.display()
+                ))
+            }
+        };
+
+        fs::write(path, bytes).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))
+    }
+}
@@ -0,0 +1,210 @@
+use crate::utils::grid::{Grid, Tile};
+use crate::utils::nalgebra::VectorHelpers;
+use crate::utils::render::{Color, Colored, BLACK};
+use nalgebra::{Scalar, Vector2};
+use std::env;
+use std::fmt;
+use std::sync::OnceLock;
+
+const RESET: &str = "\x1b[0m";
+
+// How a canvas is written out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorMode {
+    Ansi,
+    // Highlighted tiles are replaced by the marker of their overlay
+    Plain,
+}
+
+// Canvases are only displayed in diagnostics, whose destination is known once logging starts
+static DISPLAY_MODE: OnceLock<ColorMode> = OnceLock::new();
+
+impl ColorMode {
+    // ANSI colors when writing to a terminal, unless NO_COLOR is set
+    pub fn detect(terminal: bool) -> Self {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+        match terminal && !no_color {
+            true => ColorMode::Ansi,
+            false => ColorMode::Plain,
+        }
+    }
+
+    // The mode of displayed canvases, plain until it is set
+    pub fn display() -> Self {
+        DISPLAY_MODE.get().copied().unwrap_or(ColorMode::Plain)
+    }
+
+    pub fn set_display(mode: Self) {
+        let _ = DISPLAY_MODE.set(mode);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Cell {
+    symbol: char,
+    color: Option<Color>,
+    // The index of the overlay that highlights the cell, later overlays win
+    overlay: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Overlay {
+    marker: char,
+    color: Color,
+    label: String,
+}
+
+// A grid of colored characters with highlighted overlays, for printing to the terminal
+#[derive(Clone, Debug, PartialEq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    overlays: Vec<Overlay>,
+    legend: Vec<(char, Color, String)>,
+}
+
+fn foreground(color: Color) -> String {
+    format!("\x1b[38;2;{};{};{}m", color[0], color[1], color[2])
+}
+
+fn background(color: Color) -> String {
+    format!("\x1b[48;2;{};{};{}m", color[0], color[1], color[2])
+}
+
+impl Canvas {
+    pub fn from_grid_with<T: Scalar>(
+        grid: &Grid<T>,
+        style: impl Fn(&T) -> (char, Option<Color>),
+    ) -> Self {
+        let cells = grid
+            .iter()
+            .map(|(_, tile)| {
+                let (symbol, color) = style(tile);
+                Cell {
+                    symbol,
+                    color,
+                    overlay: None,
+                }
+            })
+            .collect();
+
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            cells,
+            overlays: vec![],
+            legend: vec![],
+        }
+    }
+
+    pub fn from_grid<T: Scalar + Tile>(grid: &Grid<T>) -> Self {
+        Self::from_grid_with(grid, |tile| (tile.to_char(), None))
+    }
+
+    pub fn from_colored_grid<T: Scalar + Tile + Colored>(grid: &Grid<T>) -> Self {
+        Self::from_grid_with(grid, |tile| (tile.to_char(), Some(tile.color())))
+    }
+
+    fn cell_mut(&mut self, position: &Vector2<i32>) -> Option<&mut Cell> {
+        let (row, column) = position
+            .try_to_matrix_index()
+            .filter(|&(row, column)| row < self.height && column < self.width)?;
+        Some(&mut self.cells[row * self.width + column])
+    }
+
+    // Highlights the positions without hiding their tiles, positions outside are ignored
+    pub fn with_highlight(
+        mut self,
+        positions: impl IntoIterator<Item = Vector2<i32>>,
+        marker: char,
+        color: Color,
+        label: &str,
+    ) -> Self {
+        let overlay = self.overlays.len();
+        self.overlays.push(Overlay {
+            marker,
+            color,
+            label: label.to_string(),
+        });
+
+        for position in positions {
+            if let Some(cell) = self.cell_mut(&position) {
+                cell.overlay = Some(overlay);
+            }
+        }
+        self
+    }
+
+    // Explains a tile in the legend below the grid
+    pub fn with_legend(mut self, symbol: char, color: Color, label: &str) -> Self {
+        self.legend.push((symbol, color, label.to_string()));
+        self
+    }
+
+    fn write_cell(&self, out: &mut String, cell: &Cell, mode: ColorMode) {
+        let overlay = cell.overlay.map(|i| &self.overlays[i]);
+        match (mode, overlay) {
+            (ColorMode::Plain, Some(overlay)) => out.push(overlay.marker),
+            (ColorMode::Plain, None) => out.push(cell.symbol),
+            (ColorMode::Ansi, Some(overlay)) => {
+                out.push_str(&background(overlay.color));
+                out.push_str(&foreground(BLACK));
+                out.push(cell.symbol);
+                out.push_str(RESET);
+            }
+            (ColorMode::Ansi, None) => match cell.color {
+                Some(color) => {
+                    out.push_str(&foreground(color));
+                    out.push(cell.symbol);
+                    out.push_str(RESET);
+                }
+                None => out.push(cell.symbol),
+            },
+        }
+    }
+
+    pub fn render(&self, mode: ColorMode) -> String {
+        let mut out = String::new();
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                self.write_cell(&mut out, cell, mode);
+            }
+            out.push('\n');
+        }
+
+        // Only overlays that highlight something are explained
+        let overlays = self
+            .overlays
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.cells.iter().any(|c| c.overlay == Some(*i)))
+            .map(|(_, o)| (o.marker, o.color, &o.label, true));
+        let tiles = self.legend.iter().map(|(s, c, l)| (*s, *c, l, false));
+
+        for (symbol, color, label, highlighted) in overlays.chain(tiles) {
+            let swatch = Cell {
+                symbol,
+                color: Some(color),
+                overlay: None,
+            };
+            match (mode, highlighted) {
+                (ColorMode::Ansi, true) => {
+                    out.push_str(&background(color));
+                    out.push(' ');
+                    out.push_str(RESET);
+                }
+                _ => self.write_cell(&mut out, &swatch, mode),
+            }
+            out.push_str(&format!(" {}\n", label));
+        }
+
+        out
+    }
+}
+
+impl fmt::Display for Canvas {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(&self.render(ColorMode::display()))
+    }
+}
@@ -0,0 +1,317 @@
+use crate::utils::grid::Grid;
+use crate::utils::render::gif::{self, lzw};
+use crate::utils::render::png::{adler32, crc32, zlib_stored};
+use crate::utils::render::{
+    group_color, Canvas, ColorMode, Frame, Image, Recorder, BLACK, BLUE, RED, WHITE,
+};
+use nalgebra::vector;
+use std::collections::HashSet;
+use std::path::Path;
+
+fn checkerboard() -> Image {
+    let grid = Grid::parse("#.\n.#", Some).unwrap();
+    Image::from_grid(&grid, |&c| if c == '#' { BLACK } else { WHITE })
+}
+
+// Reads the data back from stored deflate blocks, checking their lengths and the checksum
+fn unstore(stream: &[u8]) -> Vec<u8> {
+    assert_eq!(&stream[..2], &[0x78, 0x01]);
+
+    let mut data = vec![];
+    let mut offset = 2;
+    loop {
+        let last = stream[offset] == 1;
+        let length = u16::from_le_bytes([stream[offset + 1], stream[offset + 2]]);
+        let complement = u16::from_le_bytes([stream[offset + 3], stream[offset + 4]]);
+        assert_eq!(length, !complement);
+
+        offset += 5;
+        data.extend(&stream[offset..offset + length as usize]);
+        offset += length as usize;
+
+        if last {
+            break;
+        }
+    }
+
+    assert_eq!(&stream[offset..], adler32(&data).to_be_bytes());
+    data
+}
+
+#[test]
+fn test_checksums() {
+    assert_eq!(crc32(b"123456789"), 0xCBF43926);
+    assert_eq!(crc32(b"IEND"), 0xAE426082);
+    assert_eq!(adler32(b"Wikipedia"), 0x11E60398);
+}
+
+#[test]
+fn test_zlib_stored() {
+    assert_eq!(unstore(&zlib_stored(&[])), Vec::<u8>::new());
+
+    let data: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+    assert_eq!(unstore(&zlib_stored(&data)), data);
+}
+
+#[test]
+fn test_from_grid() {
+    let image = checkerboard();
+
+    assert_eq!((image.width(), image.height()), (2, 2));
+    assert_eq!(image.get(&vector![0, 0]), Some(BLACK));
+    assert_eq!(image.get(&vector![1, 0]), Some(WHITE));
+    assert_eq!(image.get(&vector![2, 0]), None);
+}
+
+#[test]
+fn test_overlay() {
+    let image = checkerboard().with_overlay([vector![1, 0], vector![5, 5]], RED);
+
+    assert_eq!(image.get(&vector![1, 0]), Some(RED));
+    assert_eq!(image.get(&vector![0, 1]), Some(WHITE));
+}
+
+#[test]
+fn test_scaled() {
+    let image = checkerboard().scaled(3);
+
+    assert_eq!((image.width(), image.height()), (6, 6));
+    assert_eq!(image.get(&vector![2, 2]), Some(BLACK));
+    assert_eq!(image.get(&vector![3, 2]), Some(WHITE));
+    assert_eq!(image.get(&vector![5, 5]), Some(BLACK));
+}
+
+#[test]
+fn test_ppm() {
+    let ppm = checkerboard().to_ppm();
+
+    assert!(ppm.starts_with(b"P6\n2 2\n255\n"));
+    assert_eq!(
+        &ppm[11..],
+        &[0, 0, 0, 255, 255, 255, 255, 255, 255, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_png() {
+    let png = checkerboard().to_png();
+
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(&png[12..16], b"IHDR");
+    assert_eq!(&png[16..24], &[0, 0, 0, 2, 0, 0, 0, 2]);
+    assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+
+    let length = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+    assert_eq!(&png[37..41], b"IDAT");
+    assert_eq!(
+        unstore(&png[41..41 + length]),
+        vec![0, 0, 0, 0, 255, 255, 255, 0, 255, 255, 255, 0, 0, 0]
+    );
+}
+
+#[test]
+fn test_save_unknown_format() {
+    assert!(checkerboard().save(Path::new("image.gif")).is_err());
+}
+
+// Decodes LZW codes the way GIF decoders do, growing the code width after the table fills up
+fn unlzw(bytes: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1usize << min_code_size;
+    let end = clear + 1;
+    let reset = || -> Vec<Vec<u8>> { (0..=end).map(|i| vec![i as u8]).collect() };
+
+    let mut table = reset();
+    let mut width = min_code_size as usize + 1;
+    let mut previous: Option<usize> = None;
+    let mut output = vec![];
+    let mut bit = 0;
+
+    loop {
+        let code = (0..width)
+            .map(|i| ((bytes[(bit + i) / 8] >> ((bit + i) % 8)) as usize & 1) << i)
+            .sum::<usize>();
+        bit += width;
+
+        if code == clear {
+            table = reset();
+            width = min_code_size as usize + 1;
+            previous = None;
+            continue;
+        }
+        if code == end {
+            break;
+        }
+
+        let entry = match (previous, table.get(code)) {
+            (_, Some(entry)) => entry.clone(),
+            (Some(previous), None) => {
+                let mut entry = table[previous].clone();
+                entry.push(entry[0]);
+                entry
+            }
+            (None, None) => panic!("unknown first code {}", code),
+        };
+
+        if let Some(previous) = previous {
+            if table.len() < 4096 {
+                let mut added = table[previous].clone();
+                added.push(entry[0]);
+                table.push(added);
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+        }
+
+        output.extend(&entry);
+        previous = Some(code);
+    }
+
+    output
+}
+
+#[test]
+fn test_lzw() {
+    assert_eq!(unlzw(&lzw(&[], 2), 2), Vec::<u8>::new());
+    assert_eq!(unlzw(&lzw(&[3], 2), 2), vec![3]);
+
+    let repeated = vec![1; 10_000];
+    assert_eq!(unlzw(&lzw(&repeated, 2), 2), repeated);
+
+    // Enough distinct runs to fill the code table several times
+    let mut state = 7u32;
+    let noise: Vec<u8> = (0..50_000)
+        .map(|_| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (state >> 16) as u8
+        })
+        .collect();
+    assert_eq!(unlzw(&lzw(&noise, 8), 8), noise);
+
+    let few_colors: Vec<u8> = noise.iter().map(|b| b % 5).collect();
+    assert_eq!(unlzw(&lzw(&few_colors, 3), 3), few_colors);
+}
+
+#[test]
+fn test_gif() {
+    let first = [BLACK, WHITE, WHITE, RED];
+    let second = [RED, RED, BLACK, BLACK];
+    let animation = gif::encode(2, 2, &[&first, &second], 25).unwrap();
+
+    assert_eq!(&animation[..6], b"GIF89a");
+    assert_eq!(&animation[6..10], &[2, 0, 2, 0]);
+    // Three colors fit a palette of four
+    assert_eq!(animation[10], 0xF1);
+    assert_eq!(
+        &animation[13..25],
+        &[0, 0, 0, 255, 255, 255, 220, 40, 40, 0, 0, 0]
+    );
+    assert_eq!(animation.last(), Some(&0x3B));
+
+    let delays = animation
+        .windows(6)
+        .filter(|w| *w == [0x21, 0xF9, 0x04, 0x00, 25, 0])
+        .count();
+    assert_eq!(delays, 2);
+}
+
+#[test]
+fn test_gif_too_many_colors() {
+    let pixels: Vec<_> = (0..300).map(|i| [i as u8, (i / 256) as u8, 0]).collect();
+    assert!(gif::encode(300, 1, &[&pixels], 10).is_err());
+}
+
+fn counter_frame(step: usize) -> Frame {
+    Frame::new(Image::new(1, 1, BLACK), format!("{}\n", step))
+}
+
+#[test]
+fn test_recorder() {
+    let mut recorder = Recorder::new(3, 2);
+    for step in 0..10 {
+        recorder.push(|| counter_frame(step));
+    }
+
+    let steps: Vec<usize> = recorder.frames().iter().map(|(step, _)| *step).collect();
+    assert_eq!(steps, vec![0, 3]);
+    assert!(recorder.is_full());
+    assert_eq!(recorder.to_text(), "step 0:\n0\n\nstep 3:\n3\n");
+}
+
+#[test]
+fn test_recorder_disabled() {
+    let mut recorder = Recorder::disabled();
+    recorder.push(|| panic!("a disabled recorder does not draw frames"));
+
+    assert!(recorder.frames().is_empty());
+    assert!(recorder.to_gif(1, 10).is_err());
+}
+
+#[test]
+fn test_recorder_gif() {
+    let mut recorder = Recorder::new(1, 10);
+    recorder.push(|| Frame::new(checkerboard(), String::new()));
+
+    let animation = recorder.to_gif(3, 10).unwrap();
+    assert_eq!(&animation[6..10], &[6, 0, 6, 0]);
+    assert!(recorder.save(Path::new("frames.png"), 1, 10).is_err());
+
+    recorder.push(|| counter_frame(1));
+    assert!(recorder.to_gif(1, 10).is_err());
+}
+
+fn canvas() -> Canvas {
+    let grid = Grid::parse("#..\n.#.", Some).unwrap();
+    Canvas::from_grid_with(&grid, |&c| (c, (c == '#').then_some(WHITE)))
+}
+
+#[test]
+fn test_canvas_plain() {
+    let canvas = canvas()
+        .with_highlight(
+            [
+                vector![1, 0],
+                vector![2, 1],
+                // Positions outside of the canvas are ignored
+                vector![9, 9],
+                vector![-1, 0],
+                vector![0, -1],
+            ],
+            'O',
+            RED,
+            "path",
+        )
+        .with_highlight([], '@', BLUE, "visited")
+        .with_legend('#', WHITE, "wall");
+
+    assert_eq!(
+        canvas.render(ColorMode::Plain),
+        "#O.\n.#O\nO path\n# wall\n"
+    );
+}
+
+#[test]
+fn test_canvas_ansi() {
+    let canvas = canvas().with_highlight([vector![0, 0]], 'O', RED, "path");
+    let ansi = canvas.render(ColorMode::Ansi);
+    let lines: Vec<&str> = ansi.lines().collect();
+
+    // The highlighted wall keeps its symbol, drawn on the color of the overlay
+    assert_eq!(lines[0], "\x1b[48;2;220;40;40m\x1b[38;2;0;0;0m#\x1b[0m..");
+    assert_eq!(lines[1], ".\x1b[38;2;255;255;255m#\x1b[0m.");
+    assert_eq!(lines[2], "\x1b[48;2;220;40;40m \x1b[0m path");
+    assert_eq!(lines.len(), 3);
+}
+
+#[test]
+fn test_canvas_display() {
+    // Without a logger writing to a terminal, displayed canvases stay plain
+    assert_eq!(canvas().to_string(), canvas().render(ColorMode::Plain));
+    assert_eq!(ColorMode::detect(false), ColorMode::Plain);
+}
+
+#[test]
+fn test_group_color() {
+    let colors: HashSet<_> = ('A'..='Z').map(|c| group_color(c as usize)).collect();
+    assert_eq!(colors.len(), 26);
+}
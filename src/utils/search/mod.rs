@@ -0,0 +1,268 @@
+use priority_queue::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SearchStats {
+    // Nodes taken from the frontier and expanded
+    pub expanded: usize,
+    // Nodes added to the frontier, or moved forward in it when a shorter path was found
+    pub pushed: usize,
+    pub max_frontier: usize,
+}
+
+// The result of a search from a single start node. Every reached node keeps all of its predecessors
+// on a shortest path, so all optimal paths can be recovered. The search stops once every goal at
+// the smallest goal distance is found, without a goal it explores everything reachable.
+#[derive(Clone, Debug)]
+pub struct Search<N> {
+    pub start: N,
+    pub distances: HashMap<N, u32>,
+    pub predecessors: HashMap<N, Vec<N>>,
+    // The goals reached at the smallest distance, in the order they were found
+    pub targets: Vec<N>,
+    pub stats: SearchStats,
+}
+
+impl<N: Eq + Hash + Clone> Search<N> {
+    fn new(start: N) -> Self {
+        Self {
+            distances: HashMap::from([(start.clone(), 0)]),
+            start,
+            predecessors: HashMap::new(),
+            targets: vec![],
+            stats: SearchStats::default(),
+        }
+    }
+
+    pub fn target(&self) -> Option<&N> {
+        self.targets.first()
+    }
+
+    pub fn distance(&self, node: &N) -> Option<u32> {
+        self.distances.get(node).copied()
+    }
+
+    // The distance of the closest goal
+    pub fn target_distance(&self) -> Option<u32> {
+        self.target().and_then(|target| self.distance(target))
+    }
+
+    // One shortest path from the start to `node`, both included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(predecessor) = self.predecessors.get(path.last().unwrap()) {
+            path.push(predecessor[0].clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.target()?)
+    }
+
+    // Every shortest path from the start to `node`, their number can grow exponentially
+    pub fn all_paths_to(&self, node: &N) -> Vec<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return vec![];
+        }
+
+        match self.predecessors.get(node) {
+            None => vec![vec![node.clone()]],
+            Some(predecessors) => predecessors
+                .iter()
+                .flat_map(|predecessor| self.all_paths_to(predecessor))
+                .map(|mut path| {
+                    path.push(node.clone());
+                    path
+                })
+                .collect(),
+        }
+    }
+
+    pub fn all_paths(&self) -> Vec<Vec<N>> {
+        self.targets
+            .iter()
+            .flat_map(|target| self.all_paths_to(target))
+            .collect()
+    }
+
+    // The number of shortest paths from the start to `node`, without listing them
+    pub fn count_paths_to(&self, node: &N) -> usize {
+        fn count<N: Eq + Hash + Clone>(
+            search: &Search<N>,
+            node: &N,
+            counts: &mut HashMap<N, usize>,
+        ) -> usize {
+            if let Some(&known) = counts.get(node) {
+                return known;
+            }
+
+            let paths = match search.predecessors.get(node) {
+                None => search.distances.contains_key(node) as usize,
+                Some(predecessors) => predecessors
+                    .iter()
+                    .map(|predecessor| count(search, predecessor, counts))
+                    .sum(),
+            };
+            counts.insert(node.clone(), paths);
+            paths
+        }
+
+        count(self, node, &mut HashMap::new())
+    }
+
+    // The (predecessor, node) edges that lie on a shortest path to any of the targets
+    pub fn optimal_edges(&self) -> Vec<(N, N)> {
+        let mut edges = vec![];
+        let mut visited: HashSet<N> = self.targets.iter().cloned().collect();
+        let mut remaining: VecDeque<N> = self.targets.iter().cloned().collect();
+
+        while let Some(node) = remaining.pop_front() {
+            for predecessor in self.predecessors.get(&node).into_iter().flatten() {
+                edges.push((predecessor.clone(), node.clone()));
+                if visited.insert(predecessor.clone()) {
+                    remaining.push_back(predecessor.clone());
+                }
+            }
+        }
+
+        edges
+    }
+
+    // Records that `node` is reached from `from` at `distance`, returns whether it is a shorter path
+    fn relax(&mut self, from: &N, node: N, distance: u32) -> bool {
+        match self.distances.get(&node) {
+            _ if node == self.start => false,
+            Some(&known) if distance > known => false,
+            Some(&known) if distance == known => {
+                self.predecessors
+                    .entry(node)
+                    .or_default()
+                    .push(from.clone());
+                false
+            }
+            _ => {
+                self.distances.insert(node.clone(), distance);
+                self.predecessors.insert(node, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    // Whether the search is done once a node at `distance` is expanded
+    fn is_past_targets(&self, distance: u32) -> bool {
+        self.target_distance().is_some_and(|best| distance > best)
+    }
+}
+
+// Shortest paths with a consistent `heuristic`, which never overestimates the remaining distance
+// and decreases by at most the cost of each step
+pub fn a_star<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u32,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u32)>,
+{
+    let mut search = Search::new(start.clone());
+    let mut settled: HashSet<N> = HashSet::new();
+    let mut frontier: PriorityQueue<N, Reverse<u32>> = PriorityQueue::new();
+
+    frontier.push(start.clone(), Reverse(heuristic(&start)));
+    search.stats.pushed += 1;
+    search.stats.max_frontier = 1;
+
+    while let Some((node, Reverse(estimate))) = frontier.pop() {
+        if search.is_past_targets(estimate) {
+            break;
+        }
+
+        settled.insert(node.clone());
+        search.stats.expanded += 1;
+
+        let distance = search.distances[&node];
+        if is_goal(&node) {
+            search.targets.push(node);
+            continue;
+        }
+
+        for (neighbor, cost) in neighbors(&node) {
+            let neighbor_distance = distance + cost;
+            if search.relax(&node, neighbor.clone(), neighbor_distance)
+                && !settled.contains(&neighbor)
+            {
+                let estimate = neighbor_distance + heuristic(&neighbor);
+                frontier.push(neighbor, Reverse(estimate));
+                search.stats.pushed += 1;
+            }
+        }
+
+        search.stats.max_frontier = search.stats.max_frontier.max(frontier.len());
+    }
+
+    search
+}
+
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u32)>,
+{
+    a_star(start, neighbors, |_| 0, is_goal)
+}
+
+// Shortest paths where every step costs one
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone());
+    let mut frontier: VecDeque<N> = VecDeque::from([start]);
+    search.stats.pushed += 1;
+    search.stats.max_frontier = 1;
+
+    while let Some(node) = frontier.pop_front() {
+        let distance = search.distances[&node];
+        if search.is_past_targets(distance) {
+            break;
+        }
+
+        search.stats.expanded += 1;
+        if is_goal(&node) {
+            search.targets.push(node);
+            continue;
+        }
+
+        for neighbor in neighbors(&node) {
+            if search.relax(&node, neighbor.clone(), distance + 1) {
+                frontier.push_back(neighbor);
+                search.stats.pushed += 1;
+            }
+        }
+
+        search.stats.max_frontier = search.stats.max_frontier.max(frontier.len());
+    }
+
+    search
+}
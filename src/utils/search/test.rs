@@ -0,0 +1,107 @@
+use crate::utils::search::{a_star, bfs, dijkstra};
+
+// A diamond with two shortest paths from 0 to 3 and a longer detour through 4
+fn edges(node: &u32) -> Vec<(u32, u32)> {
+    match node {
+        0 => vec![(1, 1), (2, 1), (4, 1)],
+        1 => vec![(3, 1)],
+        2 => vec![(3, 1)],
+        4 => vec![(3, 5)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn test_dijkstra() {
+    let search = dijkstra(0, edges, |&n| n == 3);
+
+    assert_eq!(search.target_distance(), Some(2));
+    assert_eq!(search.path().unwrap().len(), 3);
+
+    let mut paths = search.all_paths();
+    paths.sort();
+    assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+    assert_eq!(search.count_paths_to(&3), 2);
+}
+
+#[test]
+fn test_dijkstra_without_goal() {
+    let search = dijkstra(0, edges, |_| false);
+
+    assert_eq!(search.target(), None);
+    assert_eq!(search.distance(&4), Some(1));
+    assert_eq!(search.distance(&3), Some(2));
+    assert_eq!(search.stats.expanded, 5);
+}
+
+#[test]
+fn test_dijkstra_unreachable() {
+    let search = dijkstra(1, edges, |&n| n == 0);
+
+    assert_eq!(search.path(), None);
+    assert_eq!(search.distance(&3), Some(1));
+    assert!(search.all_paths().is_empty());
+}
+
+#[test]
+fn test_optimal_edges() {
+    let search = dijkstra(0, edges, |&n| n == 3);
+
+    let mut edges = search.optimal_edges();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 1), (0, 2), (1, 3), (2, 3)]);
+}
+
+// An open 10 by 10 room, from one corner to the other
+fn room(&(x, y): &(i32, i32)) -> Vec<((i32, i32), u32)> {
+    [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+        .into_iter()
+        .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+        .map(|p| (p, 1))
+        .collect()
+}
+
+#[test]
+fn test_a_star() {
+    let target = (9, 9);
+    let manhattan = |&(x, y): &(i32, i32)| (target.0 - x + target.1 - y) as u32;
+
+    let informed = a_star((0, 0), room, manhattan, |&p| p == target);
+    let uninformed = dijkstra((0, 0), room, |&p| p == target);
+
+    assert_eq!(informed.target_distance(), Some(18));
+    assert_eq!(uninformed.target_distance(), Some(18));
+    // Every path along the grid is a shortest one
+    assert_eq!(informed.count_paths_to(&target), 48620);
+    assert_eq!(uninformed.count_paths_to(&target), 48620);
+}
+
+#[test]
+fn test_a_star_expands_fewer_nodes() {
+    let target = (9, 0);
+    let manhattan = |&(x, y): &(i32, i32)| ((target.0 - x).abs() + (target.1 - y).abs()) as u32;
+
+    let informed = a_star((0, 0), room, manhattan, |&p| p == target);
+    let uninformed = dijkstra((0, 0), room, |&p| p == target);
+
+    assert_eq!(informed.path().unwrap().len(), 10);
+    assert!(informed.stats.expanded < uninformed.stats.expanded);
+}
+
+#[test]
+fn test_bfs() {
+    let neighbors = |node: &u32| edges(node).into_iter().map(|(n, _)| n);
+    let search = bfs(0, neighbors, |&n| n == 3);
+
+    assert_eq!(search.target_distance(), Some(2));
+    assert_eq!(search.count_paths_to(&3), 3);
+    assert_eq!(search.distance(&4), Some(1));
+}
+
+#[test]
+fn test_start_is_goal() {
+    let search = bfs(7, |_: &u32| vec![7], |&n| n == 7);
+
+    assert_eq!(search.path(), Some(vec![7]));
+    assert_eq!(search.all_paths(), vec![vec![7]]);
+}
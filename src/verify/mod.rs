@@ -0,0 +1,95 @@
+use crate::answers::Answers;
+use crate::inputs::{read_file, InputResolver};
+use crate::runner::{self, Status};
+use crate::solution::{Answer, Part, Solution};
+use std::fmt;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail(String),
+    Missing(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(fmt, "pass"),
+            Outcome::Fail(reason) => write!(fmt, "FAIL ({})", reason),
+            Outcome::Missing(reason) => write!(fmt, "missing ({})", reason),
+        }
+    }
+}
+
+pub fn check(expected: Option<&str>, actual: Result<Answer, String>) -> Outcome {
+    match (expected, actual) {
+        (_, Err(e)) => Outcome::Fail(e),
+        (None, Ok(actual)) => Outcome::Missing(format!("no answer recorded, got '{}'", actual)),
+        (Some(expected), Ok(actual)) if actual == expected => Outcome::Pass,
+        (Some(expected), Ok(actual)) => {
+            Outcome::Fail(format!("expected '{}', got '{}'", expected, actual))
+        }
+    }
+}
+
+// Solves the part and checks its answer, a panicking solver fails the part
+pub fn check_part(
+    expected: Option<&str>,
+    solution: &dyn Solution,
+    part: Part,
+    input: &str,
+) -> Outcome {
+    let report = runner::run_part(solution, part, input);
+    let message = report.message.unwrap_or_default();
+    let actual = match (report.answer, report.status) {
+        (Some(answer), _) => Ok(answer),
+        (None, Status::Panicked) => Err(format!("panicked: {}", message)),
+        (None, _) => Err(message),
+    };
+    check(expected, actual)
+}
+
+pub fn verify_day(
+    day: u8,
+    solution: &dyn Solution,
+    resolver: &InputResolver,
+) -> Vec<(Part, Outcome)> {
+    let parts = [Part::One, Part::Two];
+
+    let input = match resolver.find(day, "input.txt") {
+        Ok(path) => read_file(&path),
+        Err(_) => {
+            let dirs = resolver
+                .day_dirs(day)
+                .iter()
+                .map(|dir| dir.display().to_string())
+                .collect::<Vec<_>>();
+            let reason = format!("no input.txt in {}", dirs.join(" or "));
+            return parts
+                .map(|part| (part, Outcome::Missing(reason.clone())))
+                .into();
+        }
+    };
+    // An input that exists but cannot be read is a failure, not a missing input
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => return parts.map(|part| (part, Outcome::Fail(e.clone()))).into(),
+    };
+
+    // Answers are optional, every part is reported as missing without them
+    let answers = match resolver.find(day, "answers.toml") {
+        Ok(path) => Answers::load(&path),
+        Err(_) => Ok(Answers::default()),
+    };
+    let answers = match answers {
+        Ok(answers) => answers,
+        Err(e) => return parts.map(|part| (part, Outcome::Fail(e.clone()))).into(),
+    };
+
+    parts
+        .map(|part| (part, check_part(answers.get(part), solution, part, &input)))
+        .into()
+}
@@ -0,0 +1,84 @@
+use crate::error::SolveError;
+use crate::inputs::InputResolver;
+use crate::solution::{Answer, Part, Solution};
+use crate::verify::{check, verify_day, Outcome};
+use std::fs;
+use std::path::PathBuf;
+
+struct Panicky {}
+
+impl Solution for Panicky {
+    fn solve_part1(&self, _input: &str) -> Result<Answer, SolveError> {
+        Ok(42_u32.into())
+    }
+
+    fn solve_part2(&self, _input: &str) -> Result<Answer, SolveError> {
+        panic!("no path to the exit");
+    }
+}
+
+fn temp_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-verify-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    root
+}
+
+#[test]
+fn test_check() {
+    assert_eq!(check(Some("42"), Ok(42_u32.into())), Outcome::Pass);
+    assert_eq!(
+        check(Some("42"), Ok(41_u32.into())),
+        Outcome::Fail("expected '42', got '41'".into())
+    );
+    assert_eq!(
+        check(None, Ok(41_u32.into())),
+        Outcome::Missing("no answer recorded, got '41'".into())
+    );
+}
+
+#[test]
+fn test_check_error() {
+    let error = SolveError::no_solution("the maze has no exit");
+    assert_eq!(
+        check(Some("42"), Err(error.to_string())),
+        Outcome::Fail("no solution: the maze has no exit".into())
+    );
+}
+
+#[test]
+fn test_verify_day() {
+    let root = temp_root("panic");
+    fs::create_dir_all(root.join("day01")).unwrap();
+    fs::write(root.join("day01/input.txt"), "1\n").unwrap();
+    fs::write(root.join("day01/answers.toml"), "part1 = 42\npart2 = 7\n").unwrap();
+
+    assert_eq!(
+        verify_day(1, &Panicky {}, &InputResolver::new(&root)),
+        vec![
+            (Part::One, Outcome::Pass),
+            (
+                Part::Two,
+                Outcome::Fail("panicked: no path to the exit".into())
+            )
+        ]
+    );
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_verify_unreadable_input() {
+    let root = temp_root("unreadable");
+    fs::create_dir_all(root.join("day01")).unwrap();
+    fs::write(root.join("day01/input.txt"), [0xff, 0xfe]).unwrap();
+
+    let outcomes = verify_day(1, &Panicky {}, &InputResolver::new(&root));
+    assert!(outcomes
+        .iter()
+        .all(|(_, outcome)| matches!(outcome, Outcome::Fail(_))));
+
+    let outcomes = verify_day(2, &Panicky {}, &InputResolver::new(&root));
+    assert!(outcomes
+        .iter()
+        .all(|(_, outcome)| matches!(outcome, Outcome::Missing(_))));
+    fs::remove_dir_all(&root).unwrap();
+}
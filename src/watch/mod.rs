@@ -0,0 +1,254 @@
+use crate::bench::Change;
+use crate::fixtures::EXAMPLE_DAY_VAR;
+use crate::inputs::InputResolver;
+use crate::runner::{PartReport, Report, Status};
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+#[cfg(test)]
+mod test;
+
+#[derive(Debug, PartialEq)]
+pub struct WatchArgs {
+    pub day: u8,
+    pub interval: Duration,
+}
+
+impl WatchArgs {
+    pub fn new(day: u8) -> Self {
+        Self {
+            day,
+            interval: Duration::from_millis(500),
+        }
+    }
+}
+
+// Modification times of all files below the watched directories
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Snapshot {
+    // Directories that do not exist (yet) are treated as empty
+    pub fn scan(dirs: &[PathBuf]) -> Self {
+        let mut snapshot = Self::default();
+        for dir in dirs {
+            snapshot.scan_dir(dir);
+        }
+        snapshot
+    }
+
+    fn scan_dir(&mut self, dir: &Path) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+
+            if metadata.is_dir() {
+                self.scan_dir(&entry.path());
+            } else if let Ok(modified) = metadata.modified() {
+                self.files.insert(entry.path(), modified);
+            }
+        }
+    }
+
+    // Files that were added, modified or removed since `previous`
+    pub fn changes(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let mut changes: Vec<PathBuf> = self
+            .files
+            .iter()
+            .filter(|(path, modified)| previous.files.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        changes.extend(
+            previous
+                .files
+                .keys()
+                .filter(|path| !self.files.contains_key(*path))
+                .cloned(),
+        );
+        changes.sort();
+        changes
+    }
+}
+
+fn crate_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+pub fn watched_dirs(day: u8, resolver: &InputResolver) -> Vec<PathBuf> {
+    let mut dirs = vec![crate_dir().join("src").join(format!("day{}", day))];
+    dirs.extend(resolver.day_dirs(day));
+    dirs
+}
+
+pub fn describe_part(part: &PartReport, previous: Option<&PartReport>) -> String {
+    let result = match (&part.answer, &part.message) {
+        (Some(answer), _) => answer.pretty(),
+        (None, Some(message)) => message.lines().next().unwrap_or_default().to_string(),
+        (None, None) => String::new(),
+    };
+
+    let time = match previous {
+        Some(previous) if previous.status == Status::Solved && part.status == Status::Solved => {
+            Change::new(previous.time, part.time).to_string()
+        }
+        _ => format!("{:.1?}", part.time),
+    };
+
+    match part.status {
+        Status::Solved => format!("part {}: {}  [{}]", part.part, result, time),
+        status => format!("part {}: {} ({})", part.part, status, result),
+    }
+}
+
+// The watcher cannot reload its own code, so every run rebuilds and runs the day through cargo.
+// Builds go to a separate target directory, so they do not replace the binary that is watching.
+// The data directory resolves the same way, as cargo runs the day from the current directory.
+struct Watcher {
+    day: u8,
+    report_path: PathBuf,
+    previous: Option<Report>,
+}
+
+impl Watcher {
+    // Runs `cargo <subcommand>` in the profile of the watcher itself. The examples of other days
+    // are skipped when the tests run.
+    fn cargo(&self, subcommand: &str, args: &[OsString]) -> Result<Output, String> {
+        let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+
+        command
+            .arg(subcommand)
+            .arg("--quiet")
+            .arg("--manifest-path")
+            .arg(crate_dir().join("Cargo.toml"))
+            .env("CARGO_TARGET_DIR", crate_dir().join("target").join("watch"))
+            .env(EXAMPLE_DAY_VAR, self.day.to_string());
+
+        if cfg!(not(debug_assertions)) {
+            command.arg("--release");
+        }
+
+        command
+            .args(args)
+            .output()
+            .map_err(|e| format!("Failed to run cargo: {}", e))
+    }
+
+    // Runs the unit tests of the day and its examples in `data/dayN/examples`
+    fn test(&self) -> Result<(), String> {
+        let args = [
+            "--lib".into(),
+            "--".into(),
+            format!("day{}::", self.day).into(),
+            "fixtures::test::test_examples".into(),
+        ];
+        let output = self.cargo("test", &args)?;
+
+        if output.status.success() {
+            println!("sample tests passed");
+        } else {
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("sample tests failed");
+        }
+
+        Ok(())
+    }
+
+    fn solve(&mut self) -> Result<(), String> {
+        let _ = fs::remove_file(&self.report_path);
+
+        let args = [
+            "--".into(),
+            "run".into(),
+            self.day.to_string().into(),
+            "--report".into(),
+            self.report_path.clone().into(),
+        ];
+        let output = self.cargo("run", &args)?;
+
+        let report: Report = match fs::read_to_string(&self.report_path) {
+            Ok(content) => serde_json::from_str(&content)
+                .map_err(|e| format!("Failed to parse the report: {}", e))?,
+            Err(_) => {
+                eprint!("{}", String::from_utf8_lossy(&output.stderr));
+                println!("day {} did not produce a report", self.day);
+                return Ok(());
+            }
+        };
+
+        let previous_parts = self
+            .previous
+            .as_ref()
+            .and_then(|previous| previous.days.first())
+            .map(|day| day.parts.as_slice())
+            .unwrap_or_default();
+
+        for part in report.days.iter().flat_map(|day| &day.parts) {
+            let previous = previous_parts.iter().find(|p| p.part == part.part);
+            println!("{}", describe_part(part, previous));
+        }
+
+        self.previous = Some(report);
+        Ok(())
+    }
+
+    fn cycle(&mut self) -> Result<(), String> {
+        self.test()?;
+        self.solve()
+    }
+}
+
+pub fn watch(args: WatchArgs) -> Result<(), String> {
+    let resolver = InputResolver::from_env();
+    let dirs = watched_dirs(args.day, &resolver);
+    let mut watcher = Watcher {
+        day: args.day,
+        report_path: env::temp_dir().join(format!("aoc-watch-day{}.json", args.day)),
+        previous: None,
+    };
+
+    println!(
+        "Watching {}",
+        dirs.iter()
+            .map(|dir| dir.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let mut snapshot = Snapshot::scan(&dirs);
+    watcher.cycle()?;
+
+    loop {
+        thread::sleep(args.interval);
+
+        let current = Snapshot::scan(&dirs);
+        let changes = current.changes(&snapshot);
+        if changes.is_empty() {
+            continue;
+        }
+
+        // Editors tend to write in several steps, wait for the files to settle
+        thread::sleep(args.interval);
+        snapshot = Snapshot::scan(&dirs);
+
+        println!();
+        for path in changes {
+            println!("changed: {}", path.display());
+        }
+        watcher.cycle()?;
+    }
+}
@@ -0,0 +1,88 @@
+use crate::answer::Answer;
+use crate::inputs::InputResolver;
+use crate::runner::{PartReport, Status};
+use crate::solution::Part;
+use crate::watch::{describe_part, watched_dirs, Snapshot};
+use std::fs::{self, File};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+fn temp_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-watch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    root
+}
+
+fn solved(answer: i128, millis: u64) -> PartReport {
+    PartReport {
+        part: Part::One,
+        status: Status::Solved,
+        answer: Some(Answer::Integer(answer)),
+        message: None,
+        time: Duration::from_millis(millis),
+    }
+}
+
+#[test]
+fn test_snapshot_changes() {
+    let root = temp_root("snapshot");
+    fs::create_dir_all(root.join("nested")).unwrap();
+    fs::write(root.join("mod.rs"), "").unwrap();
+    fs::write(root.join("nested/test.rs"), "").unwrap();
+
+    let dirs = [root.clone(), root.join("missing")];
+    let before = Snapshot::scan(&dirs);
+    assert!(Snapshot::scan(&dirs).changes(&before).is_empty());
+
+    // Set the time explicitly, file systems may not have a fine enough resolution
+    File::options()
+        .write(true)
+        .open(root.join("mod.rs"))
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(10))
+        .unwrap();
+    fs::remove_file(root.join("nested/test.rs")).unwrap();
+    fs::write(root.join("input.txt"), "").unwrap();
+
+    assert_eq!(
+        Snapshot::scan(&dirs).changes(&before),
+        vec![
+            root.join("input.txt"),
+            root.join("mod.rs"),
+            root.join("nested/test.rs")
+        ]
+    );
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_watched_dirs() {
+    let dirs = watched_dirs(7, &InputResolver::new("data"));
+
+    assert!(dirs[0].ends_with("src/day7"));
+    assert_eq!(
+        &dirs[1..],
+        [PathBuf::from("data/day07"), PathBuf::from("data/day7")]
+    );
+}
+
+#[test]
+fn test_describe_part() {
+    assert_eq!(describe_part(&solved(42, 10), None), "part 1: 42  [10.0ms]");
+    assert_eq!(
+        describe_part(&solved(42, 5), Some(&solved(41, 10))),
+        "part 1: 42  [10.0ms -> 5.0ms (-50.0%)]"
+    );
+
+    let failed = PartReport {
+        status: Status::Panicked,
+        answer: None,
+        message: Some("no path to the exit\nat line 3".into()),
+        ..solved(0, 1)
+    };
+    assert_eq!(
+        describe_part(&failed, Some(&solved(41, 10))),
+        "part 1: panicked (no path to the exit)"
+    );
+}